@@ -0,0 +1,352 @@
+//! Local audit log of the corks this Steward has submitted or scheduled
+//!
+//! Records are kept in a SQLite database so that `steward corks history` can query them while the
+//! server is running. The database is opened on the first record and the connection is kept for
+//! the life of the process.
+
+use crate::{
+    cellars,
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{debug, error},
+    Application,
+};
+use lazy_static::lazy_static;
+use rusqlite::{params, Connection, Row, ToSql};
+use std::{path::Path, sync::Mutex};
+
+lazy_static! {
+    static ref CONNECTION: Mutex<Option<Connection>> = Mutex::new(None);
+}
+
+const CREATE_TABLE: &str = "CREATE TABLE IF NOT EXISTS corks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    timestamp TEXT NOT NULL,
+    source TEXT NOT NULL,
+    requester TEXT NOT NULL,
+    requester_fingerprint TEXT NOT NULL,
+    cellar_id TEXT NOT NULL,
+    function TEXT NOT NULL,
+    arguments TEXT NOT NULL,
+    encoded_call TEXT NOT NULL,
+    cork_id TEXT NOT NULL,
+    target_height INTEGER,
+    tx_hash TEXT NOT NULL,
+    height INTEGER NOT NULL,
+    code INTEGER NOT NULL,
+    result TEXT NOT NULL,
+    cellar_type TEXT NOT NULL DEFAULT ''
+)";
+
+/// How a cork reached this Steward
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CorkSource {
+    /// Submitted by a strategy provider through the `Submit` RPC
+    Submit,
+    /// Scheduled for a block height from the CLI or through the `Schedule` RPC
+    Schedule,
+}
+
+impl CorkSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CorkSource::Submit => "submit",
+            CorkSource::Schedule => "schedule",
+        }
+    }
+}
+
+/// A single submitted or scheduled cork
+#[derive(Clone, Debug, Default)]
+pub struct CorkRecord {
+    /// Row ID, assigned by the database
+    pub id: i64,
+    /// RFC 3339 time the record was written
+    pub timestamp: String,
+    /// `submit` or `schedule`
+    pub source: String,
+    /// Subject of the requester's client certificate, or `cli` for corks scheduled from the CLI
+    pub requester: String,
+    /// Hex-encoded SHA-256 fingerprint of the requester's client certificate
+    pub requester_fingerprint: String,
+    pub cellar_id: String,
+    /// Name of the cellar adapter the call was built and decoded with, e.g. `aave_v2_stablecoin`.
+    /// Empty if the request did not match an adapter.
+    pub cellar_type: String,
+    /// Signature of the decoded function, or `unknown` if it could not be decoded
+    pub function: String,
+    pub arguments: String,
+    /// Hex-encoded contract call
+    pub encoded_call: String,
+    pub cork_id: String,
+    /// Block height a scheduled cork targets
+    pub target_height: Option<u64>,
+    pub tx_hash: String,
+    /// Height of the block that included the transaction
+    pub height: i64,
+    /// Result code of the transaction. Zero indicates success.
+    pub code: u32,
+    /// `ok`, or the reason the cork was not accepted
+    pub result: String,
+}
+
+impl CorkRecord {
+    /// Creates a record for `encoded_call`, filling in the function and arguments decoded with the
+    /// ABI of `cellar_type`. An empty `encoded_call` is recorded with an `unknown` function.
+    pub fn new(
+        source: CorkSource,
+        requester: String,
+        requester_fingerprint: String,
+        cellar_id: String,
+        cellar_type: &str,
+        encoded_call: &[u8],
+    ) -> Self {
+        let (function, arguments) = match cellars::decode_call_as(cellar_type, encoded_call) {
+            Ok(call) => (call.signature.clone(), call.arguments_string()),
+            Err(_) if encoded_call.is_empty() => ("unknown".to_string(), String::new()),
+            Err(err) => {
+                debug!("failed to decode call for audit record: {}", err);
+                ("unknown".to_string(), String::new())
+            }
+        };
+
+        CorkRecord {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            source: source.as_str().to_string(),
+            requester,
+            requester_fingerprint,
+            cellar_id,
+            cellar_type: cellar_type.to_string(),
+            function,
+            arguments,
+            encoded_call: hex::encode(encoded_call),
+            ..Default::default()
+        }
+    }
+
+    fn from_row(row: &Row<'_>) -> rusqlite::Result<Self> {
+        Ok(CorkRecord {
+            id: row.get(0)?,
+            timestamp: row.get(1)?,
+            source: row.get(2)?,
+            requester: row.get(3)?,
+            requester_fingerprint: row.get(4)?,
+            cellar_id: row.get(5)?,
+            function: row.get(6)?,
+            arguments: row.get(7)?,
+            encoded_call: row.get(8)?,
+            cork_id: row.get(9)?,
+            target_height: row.get::<_, Option<i64>>(10)?.map(|h| h as u64),
+            tx_hash: row.get(11)?,
+            height: row.get(12)?,
+            code: row.get(13)?,
+            result: row.get(14)?,
+            cellar_type: row.get(15)?,
+        })
+    }
+}
+
+/// Criteria for querying the audit log. Unset fields match every record.
+#[derive(Clone, Debug, Default)]
+pub struct HistoryFilter {
+    pub cellar_id: Option<String>,
+    pub cork_id: Option<String>,
+    pub tx_hash: Option<String>,
+    pub requester: Option<String>,
+    /// Lowest height to match. Scheduled corks are matched by their target height and submitted
+    /// corks by the height of the block that included them.
+    pub min_height: Option<u64>,
+    /// Highest height to match
+    pub max_height: Option<u64>,
+    /// Maximum number of records to return, newest first. Zero returns every match.
+    pub limit: u32,
+}
+
+/// Writes `record` to the configured audit database. Failures are logged rather than returned so
+/// that a broken audit log never blocks a cork.
+pub async fn record(record: CorkRecord) {
+    let config = APP.config();
+    if !config.audit.enabled {
+        return;
+    }
+    let path = match config.audit_db_path() {
+        Some(p) => p,
+        None => {
+            error!("not writing cork audit record: neither audit.db_path nor keystore is set");
+            return;
+        }
+    };
+
+    let result = tokio::task::spawn_blocking(move || {
+        let mut conn = CONNECTION.lock().expect("audit connection lock poisoned");
+        if conn.is_none() {
+            *conn = Some(open(&path)?);
+        }
+
+        insert(conn.as_ref().unwrap(), &record)
+    })
+    .await;
+    match result {
+        Ok(Ok(())) => (),
+        Ok(Err(err)) => error!("failed to write cork audit record: {}", err),
+        Err(err) => error!("cork audit task failed: {}", err),
+    }
+}
+
+/// Returns the records in the audit database at `path` matching `filter`, newest first
+pub fn query(path: &Path, filter: &HistoryFilter) -> Result<Vec<CorkRecord>, Error> {
+    if !path.exists() {
+        return Err(ErrorKind::Database
+            .context(format!("audit database {} does not exist", path.display()))
+            .into());
+    }
+
+    let conn = open(path)?;
+    let mut clauses: Vec<&str> = Vec::new();
+    let mut values: Vec<Box<dyn ToSql>> = Vec::new();
+    if let Some(cellar_id) = &filter.cellar_id {
+        clauses.push("lower(cellar_id) = lower(?)");
+        values.push(Box::new(cellar_id.clone()));
+    }
+    if let Some(cork_id) = &filter.cork_id {
+        clauses.push("lower(cork_id) = lower(?)");
+        values.push(Box::new(cork_id.trim_start_matches("0x").to_string()));
+    }
+    if let Some(tx_hash) = &filter.tx_hash {
+        clauses.push("lower(tx_hash) = lower(?)");
+        values.push(Box::new(tx_hash.clone()));
+    }
+    if let Some(requester) = &filter.requester {
+        clauses.push("(requester = ? OR requester_fingerprint = lower(?))");
+        values.push(Box::new(requester.clone()));
+        values.push(Box::new(requester.clone()));
+    }
+    if let Some(min_height) = filter.min_height {
+        clauses.push("coalesce(target_height, height) >= ?");
+        values.push(Box::new(min_height as i64));
+    }
+    if let Some(max_height) = filter.max_height {
+        clauses.push("coalesce(target_height, height) <= ?");
+        values.push(Box::new(max_height as i64));
+    }
+
+    let mut sql = "SELECT * FROM corks".to_string();
+    if !clauses.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&clauses.join(" AND "));
+    }
+    sql.push_str(" ORDER BY id DESC");
+    if filter.limit > 0 {
+        sql.push_str(&format!(" LIMIT {}", filter.limit));
+    }
+
+    let mut stmt = conn.prepare(&sql)?;
+    let params = values
+        .iter()
+        .map(|v| v.as_ref())
+        .collect::<Vec<&dyn ToSql>>();
+    let rows = stmt.query_map(params.as_slice(), CorkRecord::from_row)?;
+
+    rows.collect::<rusqlite::Result<Vec<CorkRecord>>>()
+        .map_err(|e| e.into())
+}
+
+fn open(path: &Path) -> Result<Connection, Error> {
+    let conn = Connection::open(path)?;
+    conn.execute(CREATE_TABLE, [])?;
+
+    // Databases written before the cellar type was recorded lack its column
+    let has_cellar_type = conn
+        .prepare("SELECT name FROM pragma_table_info('corks') WHERE name = 'cellar_type'")?
+        .exists([])?;
+    if !has_cellar_type {
+        conn.execute(
+            "ALTER TABLE corks ADD COLUMN cellar_type TEXT NOT NULL DEFAULT ''",
+            [],
+        )?;
+    }
+
+    Ok(conn)
+}
+
+fn insert(conn: &Connection, record: &CorkRecord) -> Result<(), Error> {
+    conn.execute(
+        "INSERT INTO corks (timestamp, source, requester, requester_fingerprint, cellar_id, \
+         function, arguments, encoded_call, cork_id, target_height, tx_hash, height, code, result, \
+         cellar_type) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+        params![
+            record.timestamp,
+            record.source,
+            record.requester,
+            record.requester_fingerprint,
+            record.cellar_id,
+            record.function,
+            record.arguments,
+            record.encoded_call,
+            record.cork_id,
+            record.target_height.map(|h| h as i64),
+            record.tx_hash,
+            record.height,
+            record.code,
+            record.result,
+            record.cellar_type,
+        ],
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempdir::TempDir;
+
+    #[test]
+    fn query_filters_by_cellar_and_height() {
+        let dir = TempDir::new("audit").unwrap();
+        let path = dir.path().join("audit.sqlite");
+        let conn = open(&path).unwrap();
+        for (cellar_id, height) in [("0xa", 10), ("0xb", 20), ("0xa", 30)] {
+            let record = CorkRecord {
+                cellar_id: cellar_id.to_string(),
+                height,
+                ..Default::default()
+            };
+            insert(&conn, &record).unwrap();
+        }
+
+        let filter = HistoryFilter {
+            cellar_id: Some("0xA".to_string()),
+            min_height: Some(20),
+            ..Default::default()
+        };
+        let records = query(&path, &filter).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].height, 30);
+    }
+
+    #[test]
+    fn open_adds_cellar_type_to_existing_databases() {
+        let dir = TempDir::new("audit").unwrap();
+        let path = dir.path().join("audit.sqlite");
+        let old_schema = CREATE_TABLE.replace(",\n    cellar_type TEXT NOT NULL DEFAULT ''", "");
+        Connection::open(&path)
+            .unwrap()
+            .execute(&old_schema, [])
+            .unwrap();
+
+        let conn = open(&path).unwrap();
+        let record = CorkRecord {
+            cellar_type: "erc4626_vault".to_string(),
+            ..Default::default()
+        };
+        insert(&conn, &record).unwrap();
+        let records = query(&path, &HistoryFilter::default()).unwrap();
+
+        assert_eq!(records[0].cellar_type, "erc4626_vault");
+    }
+}
@@ -1,10 +1,100 @@
 use abscissa_core::tracing::log::info;
-use ethers::prelude::*;
-use std::result::Result;
+use ethers::{
+    abi::{self, param_type::Reader, Abi, Function, ParamType, Token},
+    prelude::*,
+    utils::id,
+};
+use std::{result::Result, sync::Arc};
+use steward_abi::erc4626_vault::Erc4626Vault;
+use steward_proto::steward::{submit_request::CallData, SubmitRequest};
 
-use crate::error::{Error, ErrorKind};
+use crate::{
+    error::{Error, ErrorKind},
+    utils,
+};
 
 pub(crate) mod aave_v2_stablecoin;
+pub(crate) mod erc4626_vault;
+
+/// Cellar families Steward can encode calls for. To support a new family, add its proto message to
+/// the `call_data` oneof of `SubmitRequest`, implement [`CellarAdapter`] for it in a module under
+/// `cellars/`, and register it here.
+static ADAPTERS: &[&dyn CellarAdapter] = &[
+    &aave_v2_stablecoin::AaveV2StablecoinAdapter,
+    &erc4626_vault::Erc4626VaultAdapter,
+];
+
+/// Parameters of cellar functions that hold an amount of the cellar's asset
+const ASSET_AMOUNT_PARAMETERS: &[&str] = &["assets", "minAssetsOut", "newLimit"];
+
+/// Encoding and validation for the contract calls of a single cellar family
+pub trait CellarAdapter: Send + Sync {
+    /// Name of the cellar family used in logs
+    fn name(&self) -> &'static str;
+
+    /// ABI of the cellar contract
+    fn abi(&self) -> &'static Abi;
+
+    /// ABI or hardhat artifact JSON of the cellar contract, used to decode custom errors
+    fn abi_json(&self) -> &'static str;
+
+    /// Whether this adapter handles the given `SubmitRequest` call data variant
+    fn supports(&self, call_data: &CallData) -> bool;
+
+    /// Name of the contract function the call data represents
+    fn function_name(&self, call_data: &CallData) -> Result<String, Error>;
+
+    /// Names of the cellar functions the call data would execute. Batching calls such as
+    /// `multicall` report the functions they wrap rather than themselves.
+    fn invoked_functions(&self, call_data: &CallData) -> Result<Vec<String>, Error> {
+        self.function_name(call_data).map(|name| vec![name])
+    }
+
+    /// Checks the call data before it is encoded. The cellar ID has already been validated.
+    fn validate(&self, _call_data: &CallData, _cellar_id: &str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// ABI-encodes the call data
+    fn encode(&self, call_data: CallData) -> Result<Vec<u8>, Error>;
+}
+
+/// Returns the adapter registered for the request's call data variant
+pub fn get_adapter(request: &SubmitRequest) -> Result<&'static dyn CellarAdapter, Error> {
+    let call_data = match &request.call_data {
+        Some(c) => c,
+        None => {
+            return Err(ErrorKind::SPCallError
+                .context("empty contract call data")
+                .into())
+        }
+    };
+
+    match ADAPTERS.iter().find(|a| a.supports(call_data)) {
+        Some(a) => Ok(*a),
+        None => Err(ErrorKind::SPCallError
+            .context("unsupported cellar type")
+            .into()),
+    }
+}
+
+/// Validates the request and encodes its call data with the adapter for its cellar family
+pub fn get_encoded_call(request: SubmitRequest) -> Result<Vec<u8>, Error> {
+    validate_cellar_id(request.cellar_id.as_str())?;
+
+    let adapter = get_adapter(&request)?;
+    let call_data = request
+        .call_data
+        .expect("call data was checked by get_adapter");
+    adapter.validate(&call_data, &request.cellar_id)?;
+    log_cellar_call(
+        adapter.name(),
+        &adapter.function_name(&call_data)?,
+        &request.cellar_id,
+    );
+
+    adapter.encode(call_data)
+}
 
 pub fn validate_cellar_id(cellar_id: &str) -> Result<(), Error> {
     if let Err(err) = cellar_id.parse::<H160>() {
@@ -23,6 +113,291 @@ pub fn log_cellar_call(cellar_name: &str, function_name: &str, cellar_id: &str)
     );
 }
 
+/// Finds the function in `abi` whose selector matches the first four bytes of `encoded_call` and
+/// returns its signature, e.g. `accrue()`.
+pub fn get_function_signature(abi: &Abi, encoded_call: &[u8]) -> Result<String, Error> {
+    Ok(format_signature(get_abi_function(abi, encoded_call)?))
+}
+
+/// A contract call decoded against one of the registered cellar ABIs
+#[derive(Clone, Debug)]
+pub struct DecodedCall {
+    /// Name of the cellar family whose ABI matched the call
+    pub cellar: &'static str,
+    /// Function name, e.g. `setDepositLimit`
+    pub name: String,
+    /// Function signature, e.g. `setDepositLimit(uint256)`
+    pub signature: String,
+    /// Parameter names in declaration order
+    pub parameters: Vec<String>,
+    /// Decoded arguments in declaration order
+    pub arguments: Vec<Token>,
+}
+
+impl DecodedCall {
+    /// Arguments rendered as a comma separated list, with integers in decimal and addresses and
+    /// bytes as 0x-prefixed hex
+    pub fn arguments_string(&self) -> String {
+        format_tokens(&self.arguments)
+    }
+
+    /// Arguments paired with their parameter names. If `asset_decimals` is set, amounts
+    /// denominated in the cellar's asset are rendered in whole units of the asset.
+    pub fn named_arguments(&self, asset_decimals: Option<u8>) -> Vec<(String, String)> {
+        self.parameters
+            .iter()
+            .zip(self.arguments.iter())
+            .map(|(name, token)| {
+                let value = match (token, asset_decimals) {
+                    (Token::Uint(v), Some(d))
+                        if ASSET_AMOUNT_PARAMETERS.contains(&name.as_str()) =>
+                    {
+                        format_units(*v, d)
+                    }
+                    _ => format_token(token),
+                };
+                (name.clone(), value)
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for DecodedCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name, self.arguments_string())
+    }
+}
+
+/// Decodes `encoded_call` with the first registered cellar ABI that has a function with a
+/// matching selector and can decode its arguments
+pub fn decode_call(encoded_call: &[u8]) -> Result<DecodedCall, Error> {
+    decode_call_all(encoded_call).map(|mut calls| calls.remove(0))
+}
+
+/// Decodes `encoded_call` with every registered cellar ABI that has a function with a matching
+/// selector. Cellar families may share selectors, e.g. `deposit(uint256,address)`, so a call can
+/// have more than one decoding.
+pub fn decode_call_all(encoded_call: &[u8]) -> Result<Vec<DecodedCall>, Error> {
+    let mut calls = Vec::new();
+    let mut last_err = None;
+    for adapter in ADAPTERS {
+        match decode_call_with(*adapter, encoded_call) {
+            Ok(c) => calls.push(c),
+            Err(err) => last_err = Some(err),
+        }
+    }
+    if calls.is_empty() {
+        return Err(last_err.unwrap_or_else(|| {
+            ErrorKind::SPCallError
+                .context("no cellar adapters registered")
+                .into()
+        }));
+    }
+
+    Ok(calls)
+}
+
+/// Decodes `encoded_call` with the ABI of the cellar family named `cellar`, as in
+/// [`DecodedCall::cellar`]
+pub fn decode_call_as(cellar: &str, encoded_call: &[u8]) -> Result<DecodedCall, Error> {
+    match ADAPTERS.iter().find(|a| a.name() == cellar) {
+        Some(adapter) => decode_call_with(*adapter, encoded_call),
+        None => Err(ErrorKind::SPCallError
+            .context(format!("unknown cellar type {}", cellar))
+            .into()),
+    }
+}
+
+fn decode_call_with(
+    adapter: &'static dyn CellarAdapter,
+    encoded_call: &[u8],
+) -> Result<DecodedCall, Error> {
+    let function = get_abi_function(adapter.abi(), encoded_call)?;
+    let arguments = function.decode_input(&encoded_call[4..]).map_err(|e| {
+        ErrorKind::AbiError.context(format!(
+            "failed to decode arguments of {}: {}",
+            function.name, e
+        ))
+    })?;
+
+    Ok(DecodedCall {
+        cellar: adapter.name(),
+        name: function.name.clone(),
+        signature: format_signature(function),
+        parameters: function.inputs.iter().map(|p| p.name.clone()).collect(),
+        arguments,
+    })
+}
+
+/// Queries the decimals of the asset held by the cellar at `cellar_id`
+pub async fn get_asset_decimals(cellar_id: &str) -> Result<u8, Error> {
+    let address = match cellar_id.parse::<H160>() {
+        Ok(a) => a,
+        Err(err) => {
+            return Err(ErrorKind::SPCallError
+                .context(format!("invalid ethereum address: {}", err))
+                .into())
+        }
+    };
+    let provider = Arc::new(utils::get_eth_provider().await?);
+    let query_failed = |e: String| {
+        ErrorKind::ContractError.context(format!(
+            "failed to query asset decimals of cellar {}: {}",
+            cellar_id, e
+        ))
+    };
+
+    // Every supported cellar family implements ERC-4626, and its asset ERC-20
+    let cellar = Erc4626Vault::new(address, provider.clone());
+    let asset = cellar
+        .asset()
+        .call()
+        .await
+        .map_err(|e| query_failed(e.to_string()))?;
+    let decimals = Erc4626Vault::new(asset, provider)
+        .decimals()
+        .call()
+        .await
+        .map_err(|e| query_failed(e.to_string()))?;
+
+    Ok(decimals)
+}
+
+fn get_abi_function<'a>(abi: &'a Abi, encoded_call: &[u8]) -> Result<&'a Function, Error> {
+    if encoded_call.len() < 4 {
+        return Err(ErrorKind::SPCallError
+            .context("encoded call is shorter than a function selector")
+            .into());
+    }
+
+    match abi
+        .functions()
+        .find(|f| f.short_signature() == encoded_call[..4])
+    {
+        Some(f) => Ok(f),
+        None => Err(ErrorKind::SPCallError
+            .context(format!(
+                "no function with selector 0x{} in abi",
+                hex::encode(&encoded_call[..4])
+            ))
+            .into()),
+    }
+}
+
+fn format_signature(function: &Function) -> String {
+    let inputs = function
+        .inputs
+        .iter()
+        .map(|p| p.kind.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    format!("{}({})", function.name, inputs)
+}
+
+/// Renders `value` in whole units given the token's `decimals`, e.g. 1500000 with 6 decimals is 1.5
+fn format_units(value: U256, decimals: u8) -> String {
+    // 10^77 is the largest power of ten that fits in a U256
+    if decimals > 77 {
+        return value.to_string();
+    }
+
+    let unit = U256::exp10(decimals as usize);
+    let fraction = format!(
+        "{:0>width$}",
+        (value % unit).to_string(),
+        width = decimals as usize
+    );
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        return (value / unit).to_string();
+    }
+
+    format!("{}.{}", value / unit, fraction)
+}
+
+fn format_tokens(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(format_token)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn format_token(token: &Token) -> String {
+    match token {
+        Token::Address(a) => format!("{:?}", a),
+        Token::Bytes(b) | Token::FixedBytes(b) => format!("0x{}", hex::encode(b)),
+        Token::Int(i) | Token::Uint(i) => i.to_string(),
+        Token::Bool(b) => b.to_string(),
+        Token::String(s) => s.clone(),
+        Token::Array(t) | Token::FixedArray(t) => format!("[{}]", format_tokens(t)),
+        Token::Tuple(t) => format!("({})", format_tokens(t)),
+    }
+}
+
+/// Builds a readable reason from the data returned by a reverted call. Handles `Error(string)`
+/// reverts and the custom errors declared in `abi_json`, the contract's ABI or hardhat artifact.
+pub fn decode_revert(abi_json: &str, data: &[u8]) -> String {
+    if data.len() < 4 {
+        return "reverted without a reason".to_string();
+    }
+
+    let (selector, args) = data.split_at(4);
+    if selector == &id("Error(string)")[..] {
+        if let Ok(tokens) = abi::decode(&[ParamType::String], args) {
+            return tokens[0].to_string();
+        }
+    }
+
+    for (name, params) in get_custom_errors(abi_json) {
+        let types = params
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        if selector != &id(format!("{}({})", name, types))[..] {
+            continue;
+        }
+
+        if let Ok(tokens) = abi::decode(&params, args) {
+            let values = tokens
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            return format!("{}({})", name, values);
+        }
+    }
+
+    format!("unrecognized revert data 0x{}", hex::encode(data))
+}
+
+fn get_custom_errors(abi_json: &str) -> Vec<(String, Vec<ParamType>)> {
+    let artifact: serde_json::Value = match serde_json::from_str(abi_json) {
+        Ok(v) => v,
+        Err(_) => return Vec::new(),
+    };
+    let entries = match artifact.get("abi").unwrap_or(&artifact).as_array() {
+        Some(e) => e.clone(),
+        None => return Vec::new(),
+    };
+
+    entries
+        .iter()
+        .filter(|e| e["type"] == "error")
+        .filter_map(|e| {
+            let name = e["name"].as_str()?.to_string();
+            let params = e["inputs"]
+                .as_array()?
+                .iter()
+                .map(|i| Reader::read(i["type"].as_str()?).ok())
+                .collect::<Option<Vec<ParamType>>>()?;
+            Some((name, params))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,4 +417,157 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn function_signature_matches_selector() {
+        let abi = &steward_abi::aave_v2_stablecoin::AAVEV2STABLECOINCELLAR_ABI;
+        let result = get_function_signature(abi, &[0x15, 0xf4, 0xc6, 0x11]).unwrap();
+
+        assert_eq!(result, "rebalance(address[9],uint256[3][4],uint256)");
+    }
+
+    #[test]
+    fn unknown_selector_errors() {
+        let abi = &steward_abi::aave_v2_stablecoin::AAVEV2STABLECOINCELLAR_ABI;
+        let result = get_function_signature(abi, &[0xde, 0xad, 0xbe, 0xef]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_revert_string() {
+        let mut data = id("Error(string)").to_vec();
+        data.extend(abi::encode(&[abi::Token::String(
+            "not allowed".to_string(),
+        )]));
+        let result = decode_revert(aave_v2_stablecoin::ABI_JSON, &data);
+
+        assert_eq!(result, "not allowed");
+    }
+
+    #[test]
+    fn decode_revert_custom_error() {
+        let mut data = id("USR_UntrustedPosition(address)").to_vec();
+        data.extend(abi::encode(&[abi::Token::Address(H160::zero())]));
+        let result = decode_revert(aave_v2_stablecoin::ABI_JSON, &data);
+
+        assert_eq!(
+            result,
+            "USR_UntrustedPosition(0000000000000000000000000000000000000000)"
+        );
+    }
+
+    #[test]
+    fn erc4626_vault_request_uses_vault_adapter() {
+        use steward_proto::steward::{
+            erc4626_vault::{Function, Withdraw},
+            Erc4626Vault,
+        };
+
+        let request = SubmitRequest {
+            cellar_id: "0x0000000000000000000000000000000000000001".to_string(),
+            call_data: Some(CallData::Erc4626Vault(Erc4626Vault {
+                function: Some(Function::Withdraw(Withdraw {
+                    assets: "1000".to_string(),
+                    receiver: "0x0000000000000000000000000000000000000001".to_string(),
+                    owner: "0x0000000000000000000000000000000000000002".to_string(),
+                })),
+            })),
+            ..Default::default()
+        };
+        let adapter = get_adapter(&request).unwrap();
+        let encoded = adapter.encode(request.call_data.unwrap()).unwrap();
+
+        assert_eq!(adapter.name(), "erc4626_vault");
+        assert_eq!(
+            get_function_signature(adapter.abi(), &encoded).unwrap(),
+            "withdraw(uint256,address,address)"
+        );
+    }
+
+    #[test]
+    fn multicall_reports_invalid_call_index() {
+        use steward_proto::steward::{
+            aave_v2_stablecoin::{Accrue, Function, Reinvest},
+            AaveV2Stablecoin, AaveV2StablecoinMulticall,
+        };
+
+        let call = |f| AaveV2Stablecoin { function: Some(f) };
+        let mut request = SubmitRequest {
+            cellar_id: "0x0000000000000000000000000000000000000000".to_string(),
+            call_data: Some(CallData::AaveV2StablecoinMulticall(
+                AaveV2StablecoinMulticall {
+                    function_calls: vec![
+                        call(Function::Accrue(Accrue {})),
+                        call(Function::Reinvest(Reinvest {
+                            min_assets_out: "not a number".to_string(),
+                        })),
+                    ],
+                },
+            )),
+            ..Default::default()
+        };
+        let result = get_encoded_call(request.clone());
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("function_calls[1]"));
+
+        request.call_data = Some(CallData::AaveV2StablecoinMulticall(
+            AaveV2StablecoinMulticall {
+                function_calls: vec![call(Function::Accrue(Accrue {}))],
+            },
+        ));
+        let encoded = get_encoded_call(request).unwrap();
+        let abi = &steward_abi::aave_v2_stablecoin::AAVEV2STABLECOINCELLAR_ABI;
+
+        assert_eq!(
+            get_function_signature(abi, &encoded).unwrap(),
+            "multicall(bytes[])"
+        );
+    }
+
+    #[test]
+    fn decode_call_formats_arguments() {
+        let mut data = id("setDepositLimit(uint256)").to_vec();
+        data.extend(abi::encode(&[Token::Uint(1000.into())]));
+        let call = decode_call(&data).unwrap();
+
+        assert_eq!(call.cellar, "aave_v2_stablecoin");
+        assert_eq!(call.signature, "setDepositLimit(uint256)");
+        assert_eq!(call.to_string(), "setDepositLimit(1000)");
+    }
+
+    #[test]
+    fn decode_call_all_returns_each_matching_cellar() {
+        let mut data = id("deposit(uint256,address)").to_vec();
+        data.extend(abi::encode(&[
+            Token::Uint(1000.into()),
+            Token::Address(H160::zero()),
+        ]));
+        let cellars: Vec<&str> = decode_call_all(&data)
+            .unwrap()
+            .iter()
+            .map(|c| c.cellar)
+            .collect();
+
+        assert_eq!(cellars, vec!["aave_v2_stablecoin", "erc4626_vault"]);
+    }
+
+    #[test]
+    fn named_arguments_apply_asset_decimals() {
+        let mut data = id("setDepositLimit(uint256)").to_vec();
+        data.extend(abi::encode(&[Token::Uint(1_500_000.into())]));
+        let call = decode_call(&data).unwrap();
+
+        assert_eq!(
+            call.named_arguments(Some(6)),
+            vec![("newLimit".to_string(), "1.5".to_string())]
+        );
+        assert_eq!(
+            call.named_arguments(None),
+            vec![("newLimit".to_string(), "1500000".to_string())]
+        );
+    }
 }
@@ -3,74 +3,164 @@ use crate::{
     utils::{sp_call_error, string_to_u256},
 };
 use ethers::{
-    abi::AbiEncode,
+    abi::{Abi, AbiEncode},
     contract::EthCall,
-    prelude::{H160, U256},
+    prelude::{Bytes, H160, U256},
 };
 use std::convert::TryInto;
 use steward_abi::aave_v2_stablecoin::*;
-use steward_proto::steward::aave_v2_stablecoin::Function::{self, *};
+use steward_proto::steward::{
+    aave_v2_stablecoin::Function::{self, *},
+    submit_request::CallData,
+    AaveV2Stablecoin,
+};
 
-use super::log_cellar_call;
+use super::CellarAdapter;
 
-const CELLAR_NAME: &str = "aave_v2_stablecoin";
+/// Hardhat artifact of the cellar contract, used to decode its custom errors
+pub const ABI_JSON: &str = include_str!("../../../steward_abi/AaveV2StablecoinCellar.json");
+/// Adapter name, also used to label the corks scheduled for these cellars from the CLI
+pub const CELLAR_NAME: &str = "aave_v2_stablecoin";
 const LOG_PREFIX: &str = "AaveV2StablcoinCellar";
 
-pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>, Error> {
+pub struct AaveV2StablecoinAdapter;
+
+impl CellarAdapter for AaveV2StablecoinAdapter {
+    fn name(&self) -> &'static str {
+        CELLAR_NAME
+    }
+
+    fn abi(&self) -> &'static Abi {
+        &AAVEV2STABLECOINCELLAR_ABI
+    }
+
+    fn abi_json(&self) -> &'static str {
+        ABI_JSON
+    }
+
+    fn supports(&self, call_data: &CallData) -> bool {
+        matches!(
+            call_data,
+            CallData::AaveV2Stablecoin(_) | CallData::AaveV2StablecoinMulticall(_)
+        )
+    }
+
+    fn function_name(&self, call_data: &CallData) -> Result<String, Error> {
+        if let CallData::AaveV2StablecoinMulticall(_) = call_data {
+            return Ok(MulticallCall::function_name().to_string());
+        }
+
+        Ok(get_function_name(get_function(call_data)?))
+    }
+
+    fn invoked_functions(&self, call_data: &CallData) -> Result<Vec<String>, Error> {
+        if let CallData::AaveV2StablecoinMulticall(multicall) = call_data {
+            let functions = get_multicall_functions(&multicall.function_calls)?;
+            return Ok(functions.into_iter().map(get_function_name).collect());
+        }
+
+        Ok(vec![get_function_name(get_function(call_data)?)])
+    }
+
+    fn validate(&self, call_data: &CallData, _cellar_id: &str) -> Result<(), Error> {
+        if let CallData::AaveV2StablecoinMulticall(multicall) = call_data {
+            return get_multicall_functions(&multicall.function_calls).map(|_| ());
+        }
+
+        get_function(call_data).map(|_| ())
+    }
+
+    fn encode(&self, call_data: CallData) -> Result<Vec<u8>, Error> {
+        if let CallData::AaveV2StablecoinMulticall(multicall) = call_data {
+            let functions = get_multicall_functions(&multicall.function_calls)?;
+            return get_encoded_multicall(functions.into_iter().cloned().collect());
+        }
+
+        let function = get_function(&call_data)?.clone();
+
+        get_encoded_call(function)
+    }
+}
+
+fn get_function(call_data: &CallData) -> Result<&Function, Error> {
+    match call_data {
+        CallData::AaveV2Stablecoin(call) => match &call.function {
+            Some(f) => Ok(f),
+            None => Err(sp_call_error("empty function data".to_string())),
+        },
+        _ => Err(sp_call_error(format!(
+            "call data is not for an {} cellar",
+            CELLAR_NAME
+        ))),
+    }
+}
+
+/// Unwraps the function of each call in a multicall batch, reporting the index of the first call
+/// that has none.
+fn get_multicall_functions(calls: &[AaveV2Stablecoin]) -> Result<Vec<&Function>, Error> {
+    if calls.is_empty() {
+        return Err(sp_call_error(format!(
+            "{}: multicall must contain at least one function call",
+            LOG_PREFIX
+        )));
+    }
+
+    calls
+        .iter()
+        .enumerate()
+        .map(|(i, call)| match &call.function {
+            Some(f) => Ok(f),
+            None => Err(sp_call_error(format!(
+                "{}: function_calls[{}]: empty function data",
+                LOG_PREFIX, i
+            ))),
+        })
+        .collect()
+}
+
+pub fn get_function_name(function: &Function) -> String {
+    match function {
+        Accrue(_) => AccrueCall::function_name(),
+        ClaimAndUnstake(_) => ClaimAndUnstakeCall::function_name(),
+        EnterPosition(_) => EnterPositionCall::function_name(),
+        EnterPositionWithAssets(_) => EnterPositionWithAssetsCall::function_name(),
+        ExitPosition(_) => ExitPositionCall::function_name(),
+        ExitPositionWithAssets(_) => ExitPositionWithAssetsCall::function_name(),
+        Rebalance(_) => RebalanceCall::function_name(),
+        Reinvest(_) => ReinvestCall::function_name(),
+        SetAccrualPeriod(_) => SetAccrualPeriodCall::function_name(),
+        SetDepositLimit(_) => SetDepositLimitCall::function_name(),
+        SetLiquidityLimit(_) => SetLiquidityLimitCall::function_name(),
+        SendFees(_) => SendFeesCall::function_name(),
+    }
+    .to_string()
+}
+
+pub fn get_encoded_call(function: Function) -> Result<Vec<u8>, Error> {
     match function {
         Accrue(_) => {
-            log_cellar_call(
-                CELLAR_NAME,
-                &AccrueCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = AccrueCall {};
             Ok(AaveV2StablecoinCellarCalls::Accrue(call).encode())
         }
         ClaimAndUnstake(_) => {
-            log_cellar_call(
-                CELLAR_NAME,
-                &ClaimAndUnstakeCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = ClaimAndUnstakeCall {};
             Ok(AaveV2StablecoinCellarCalls::ClaimAndUnstake(call).encode())
         }
         EnterPosition(_) => {
-            log_cellar_call(
-                CELLAR_NAME,
-                &EnterPositionCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = EnterPositionCall {};
             Ok(AaveV2StablecoinCellarCalls::EnterPosition(call).encode())
         }
         EnterPositionWithAssets(params) => {
             let assets = string_to_u256(params.assets)?;
-            log_cellar_call(
-                CELLAR_NAME,
-                &EnterPositionWithAssetsCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = EnterPositionWithAssetsCall { assets };
             Ok(AaveV2StablecoinCellarCalls::EnterPositionWithAssets(call).encode())
         }
         ExitPosition(_) => {
-            log_cellar_call(
-                CELLAR_NAME,
-                &ExitPositionCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = ExitPositionCall {};
             Ok(AaveV2StablecoinCellarCalls::ExitPosition(call).encode())
         }
         ExitPositionWithAssets(params) => {
             let assets = string_to_u256(params.assets)?;
-            log_cellar_call(
-                CELLAR_NAME,
-                &ExitPositionWithAssetsCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = ExitPositionWithAssetsCall { assets };
             Ok(AaveV2StablecoinCellarCalls::ExitPositionWithAssets(call).encode())
         }
@@ -122,11 +212,6 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
 
             let min_assets_out = string_to_u256(params.min_assets_out)?;
 
-            log_cellar_call(
-                CELLAR_NAME,
-                &RebalanceCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = RebalanceCall {
                 route,
                 swap_params,
@@ -136,56 +221,53 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
         }
         Reinvest(params) => {
             let min_assets_out = string_to_u256(params.min_assets_out)?;
-            log_cellar_call(
-                CELLAR_NAME,
-                &ReinvestCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = ReinvestCall { min_assets_out };
             Ok(AaveV2StablecoinCellarCalls::Reinvest(call).encode())
         }
         SetAccrualPeriod(params) => {
             let new_accrual_period = params.new_accrual_period;
-            log_cellar_call(
-                CELLAR_NAME,
-                &SetAccrualPeriodCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = SetAccrualPeriodCall { new_accrual_period };
             Ok(AaveV2StablecoinCellarCalls::SetAccrualPeriod(call).encode())
         }
         SetDepositLimit(params) => {
             let new_limit = string_to_u256(params.limit)?;
-            log_cellar_call(
-                CELLAR_NAME,
-                &SetDepositLimitCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = SetDepositLimitCall { new_limit };
             Ok(AaveV2StablecoinCellarCalls::SetDepositLimit(call).encode())
         }
         SetLiquidityLimit(params) => {
             let new_limit = string_to_u256(params.limit)?;
-            log_cellar_call(
-                CELLAR_NAME,
-                &SetLiquidityLimitCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = SetLiquidityLimitCall { new_limit };
             Ok(AaveV2StablecoinCellarCalls::SetLiquidityLimit(call).encode())
         }
         SendFees(_) => {
-            log_cellar_call(
-                CELLAR_NAME,
-                &SendFeesCall::function_name(),
-                cellar_id.as_str(),
-            );
             let call = SendFeesCall {};
             Ok(AaveV2StablecoinCellarCalls::SendFees(call).encode())
         }
     }
 }
 
+/// Encodes each function and wraps them in a single `multicall(bytes[])` call. Errors are prefixed
+/// with the index of the function that failed to encode.
+pub fn get_encoded_multicall(functions: Vec<Function>) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::with_capacity(functions.len());
+    for (i, function) in functions.into_iter().enumerate() {
+        match get_encoded_call(function) {
+            Ok(encoded) => data.push(Bytes::from(encoded)),
+            Err(err) => {
+                // Report the underlying message rather than repeating the error kind
+                let reason = match std::error::Error::source(&err) {
+                    Some(source) => source.to_string(),
+                    None => err.to_string(),
+                };
+                return Err(sp_call_error(format!("function_calls[{}]: {}", i, reason)));
+            }
+        }
+    }
+
+    let call = MulticallCall { data };
+    Ok(AaveV2StablecoinCellarCalls::Multicall(call).encode())
+}
+
 fn validate_route(results: Vec<Result<H160, &String>>) -> Result<(), Error> {
     let mut bad_addresses_string = String::new();
     for r in results {
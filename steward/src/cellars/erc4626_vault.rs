@@ -0,0 +1,168 @@
+use crate::{
+    application::APP,
+    error::{Error, ErrorKind},
+    utils::{format_eth_address, sp_call_error, string_to_address, string_to_u256},
+};
+use abscissa_core::Application;
+use ethers::{
+    abi::{Abi, AbiEncode},
+    contract::EthCall,
+    types::H160,
+};
+use steward_abi::erc4626_vault::*;
+use steward_proto::steward::{
+    erc4626_vault::Function::{self, *},
+    submit_request::CallData,
+};
+
+use super::CellarAdapter;
+
+/// Hardhat artifact of the ERC-4626 vault contract
+pub const ABI_JSON: &str = include_str!("../../../steward_abi/Erc4626Vault.json");
+const CELLAR_NAME: &str = "erc4626_vault";
+
+pub struct Erc4626VaultAdapter;
+
+impl CellarAdapter for Erc4626VaultAdapter {
+    fn name(&self) -> &'static str {
+        CELLAR_NAME
+    }
+
+    fn abi(&self) -> &'static Abi {
+        &ERC4626VAULT_ABI
+    }
+
+    fn abi_json(&self) -> &'static str {
+        ABI_JSON
+    }
+
+    fn supports(&self, call_data: &CallData) -> bool {
+        matches!(call_data, CallData::Erc4626Vault(_))
+    }
+
+    fn function_name(&self, call_data: &CallData) -> Result<String, Error> {
+        Ok(get_function_name(get_function(call_data)?))
+    }
+
+    fn validate(&self, call_data: &CallData, cellar_id: &str) -> Result<(), Error> {
+        let function = get_function(call_data)?;
+        let gravity = match APP.config().gravity.contract.parse::<H160>() {
+            Ok(a) => a,
+            Err(err) => {
+                return Err(ErrorKind::Config
+                    .context(format!("invalid gravity contract address: {}", err))
+                    .into())
+            }
+        };
+
+        validate_addresses(function, cellar_id, gravity)
+    }
+
+    fn encode(&self, call_data: CallData) -> Result<Vec<u8>, Error> {
+        let function = get_function(&call_data)?.clone();
+
+        get_encoded_call(function)
+    }
+}
+
+fn get_function(call_data: &CallData) -> Result<&Function, Error> {
+    match call_data {
+        CallData::Erc4626Vault(call) => match &call.function {
+            Some(f) => Ok(f),
+            None => Err(sp_call_error("empty function data".to_string())),
+        },
+        _ => Err(sp_call_error(format!(
+            "call data is not for an {} cellar",
+            CELLAR_NAME
+        ))),
+    }
+}
+
+// Corks are executed with the Gravity contract as msg.sender, which makes it the caller spending
+// shares in `withdraw` and `redeem`. Assets may only be sent to the cellar itself, and shares the
+// bridge holds on behalf of its users may never be spent.
+fn validate_addresses(function: &Function, cellar_id: &str, gravity: H160) -> Result<(), Error> {
+    let (receiver, owner) = match function {
+        Withdraw(params) => (&params.receiver, &params.owner),
+        Redeem(params) => (&params.receiver, &params.owner),
+    };
+    let cellar = string_to_address(cellar_id.to_string())?;
+    let receiver = string_to_address(receiver.clone())?;
+    let owner = string_to_address(owner.clone())?;
+
+    if receiver != cellar {
+        return Err(sp_call_error(format!(
+            "receiver {} is not the target cellar",
+            format_eth_address(receiver)
+        )));
+    }
+    if owner == gravity {
+        return Err(sp_call_error(
+            "owner may not be the gravity contract".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn get_function_name(function: &Function) -> String {
+    match function {
+        Withdraw(_) => WithdrawCall::function_name(),
+        Redeem(_) => RedeemCall::function_name(),
+    }
+    .to_string()
+}
+
+pub fn get_encoded_call(function: Function) -> Result<Vec<u8>, Error> {
+    match function {
+        Withdraw(params) => {
+            let assets = string_to_u256(params.assets)?;
+            let receiver = string_to_address(params.receiver)?;
+            let owner = string_to_address(params.owner)?;
+            let call = WithdrawCall {
+                assets,
+                receiver,
+                owner,
+            };
+            Ok(Erc4626VaultCalls::Withdraw(call).encode())
+        }
+        Redeem(params) => {
+            let shares = string_to_u256(params.shares)?;
+            let receiver = string_to_address(params.receiver)?;
+            let owner = string_to_address(params.owner)?;
+            let call = RedeemCall {
+                shares,
+                receiver,
+                owner,
+            };
+            Ok(Erc4626VaultCalls::Redeem(call).encode())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steward_proto::steward::erc4626_vault::Withdraw;
+
+    const CELLAR: &str = "0x0000000000000000000000000000000000000001";
+    const GRAVITY: &str = "0x0000000000000000000000000000000000000002";
+    const DEPOSITOR: &str = "0x0000000000000000000000000000000000000003";
+
+    fn withdraw(receiver: &str, owner: &str) -> Function {
+        Function::Withdraw(Withdraw {
+            assets: "1000".to_string(),
+            receiver: receiver.to_string(),
+            owner: owner.to_string(),
+        })
+    }
+
+    #[test]
+    fn withdrawals_must_return_assets_to_the_cellar() {
+        let gravity = GRAVITY.parse().unwrap();
+
+        assert!(validate_addresses(&withdraw(CELLAR, DEPOSITOR), CELLAR, gravity).is_ok());
+        assert!(validate_addresses(&withdraw(DEPOSITOR, DEPOSITOR), CELLAR, gravity).is_err());
+        assert!(validate_addresses(&withdraw(CELLAR, GRAVITY), CELLAR, gravity).is_err());
+    }
+}
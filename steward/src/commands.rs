@@ -11,6 +11,7 @@
 //! application's configuration file.
 
 mod config_cmd;
+mod corks;
 mod cosmos_to_eth;
 mod deploy;
 mod eth_to_cosmos;
@@ -38,6 +39,8 @@ pub enum StewardCmd {
     Keys(KeysCmd),
     /// Print default configurations
     PrintConfig(ConfigCmd),
+    #[clap(subcommand, alias = "cork")]
+    Corks(corks::CorksCmd),
     CosmosToEth(cosmos_to_eth::CosmosToEthCmd),
     #[clap(subcommand)]
     Deploy(deploy::DeployCmd),
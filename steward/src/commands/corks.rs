@@ -0,0 +1,11 @@
+mod decode;
+mod history;
+
+use abscissa_core::{clap::Parser, Command, Runnable};
+
+/// Cork audit commands
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum CorksCmd {
+    Decode(decode::DecodeCmd),
+    History(history::HistoryCmd),
+}
@@ -0,0 +1,116 @@
+use crate::{
+    application::APP,
+    cellars::{self, DecodedCall},
+    cork,
+    prelude::*,
+};
+use abscissa_core::{clap::Parser, Command, Runnable};
+use ethers::abi::Token;
+use somm_proto::cork::Cork;
+
+/// Cork decode subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Decode the contract call of a cork into the cellar function and arguments it invokes.\n Use this to verify corks submitted by other validators or referenced by governance proposals.\n If the call matches functions of more than one cellar type, each decoding is shown.\n Asset amounts are shown in whole units of the cellar's asset, whose decimals are queried from ethereum.rpc unless --decimals is given."
+)]
+pub struct DecodeCmd {
+    /// Target cellar of the cork.
+    #[clap(short, long)]
+    contract: String,
+
+    /// Hex-encoded contract call, with or without a 0x prefix.
+    #[clap(long)]
+    calldata: String,
+
+    /// Decimals of the cellar's asset. Skips the Ethereum query.
+    #[clap(short, long)]
+    decimals: Option<u8>,
+
+    /// Show asset amounts in the asset's smallest unit without querying Ethereum.
+    #[clap(long, conflicts_with = "decimals")]
+    raw: bool,
+}
+
+impl Runnable for DecodeCmd {
+    fn run(&self) {
+        abscissa_tokio::run_with_actix(&APP, async {
+            cellars::validate_cellar_id(self.contract.as_str()).unwrap_or_else(|err| {
+                status_err!("Can't validate contract address format: {}", err);
+                std::process::exit(1);
+            });
+            let encoded_call = hex::decode(self.calldata.trim().trim_start_matches("0x"))
+                .unwrap_or_else(|err| {
+                    status_err!("invalid calldata: {}", err);
+                    std::process::exit(1);
+                });
+            let calls = cellars::decode_call_all(&encoded_call).unwrap_or_else(|err| {
+                status_err!("failed to decode calldata: {}", err);
+                std::process::exit(1);
+            });
+
+            let decimals = match (self.raw, self.decimals) {
+                (true, _) => None,
+                (false, Some(d)) => Some(d),
+                (false, None) => match cellars::get_asset_decimals(&self.contract).await {
+                    Ok(d) => Some(d),
+                    Err(err) => {
+                        status_warn!("showing raw asset amounts: {}", err);
+                        None
+                    }
+                },
+            };
+
+            let cork = Cork {
+                encoded_contract_call: encoded_call,
+                target_contract_address: self.contract.clone(),
+            };
+            if let Ok(id) = cork::get_cork_id(&cork) {
+                println!("cork ID:   {}", hex::encode(id));
+            }
+            if calls.len() > 1 {
+                status_warn!(
+                    "calldata matches functions of {} cellar types, showing each decoding",
+                    calls.len()
+                );
+            }
+            for call in &calls {
+                println!(
+                    "cellar:    {} ({})",
+                    cork.target_contract_address, call.cellar
+                );
+                print_call(call, decimals, 0);
+            }
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            std::process::exit(1);
+        });
+    }
+}
+
+/// Prints the function and arguments of `call`, expanding the calls batched by `multicall` with
+/// the same cellar ABI
+fn print_call(call: &DecodedCall, decimals: Option<u8>, depth: usize) {
+    let indent = "  ".repeat(depth);
+    println!("{}function:  {}", indent, call.signature);
+    if call.name == "multicall" {
+        if let Some(Token::Array(calls)) = call.arguments.first() {
+            for (i, inner) in calls.iter().enumerate() {
+                let data = match inner {
+                    Token::Bytes(b) => b,
+                    _ => continue,
+                };
+                println!("{}call {}:", indent, i);
+                match cellars::decode_call_as(call.cellar, data) {
+                    Ok(c) => print_call(&c, decimals, depth + 1),
+                    Err(err) => println!("{}  undecodable: {}", indent, err),
+                }
+            }
+            return;
+        }
+    }
+
+    for (name, value) in call.named_arguments(decimals) {
+        println!("{}  {}: {}", indent, name, value);
+    }
+}
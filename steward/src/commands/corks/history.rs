@@ -0,0 +1,95 @@
+use crate::{
+    application::APP,
+    audit::{self, HistoryFilter},
+    prelude::*,
+};
+use abscissa_core::{clap::Parser, Command, Runnable};
+use std::path::PathBuf;
+
+/// Cork history subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Show corks submitted or scheduled by this Steward.\n This command queries the local audit database, newest first."
+)]
+pub struct HistoryCmd {
+    /// Only show corks for this cellar.
+    #[clap(long)]
+    cellar: Option<String>,
+
+    /// Only show the cork with this ID.
+    #[clap(long)]
+    cork_id: Option<String>,
+
+    /// Only show corks sent in this transaction.
+    #[clap(short, long)]
+    tx_hash: Option<String>,
+
+    /// Only show corks from the client with this certificate subject or fingerprint.
+    #[clap(short, long)]
+    requester: Option<String>,
+
+    /// Only show corks at or above this height. Scheduled corks are matched by their target height.
+    #[clap(long)]
+    min_height: Option<u64>,
+
+    /// Only show corks at or below this height. Scheduled corks are matched by their target height.
+    #[clap(long)]
+    max_height: Option<u64>,
+
+    /// Maximum number of corks to show. Zero shows all of them.
+    #[clap(short, long, default_value = "20")]
+    limit: u32,
+
+    /// Path of the audit database. Defaults to the audit database in the config.
+    #[clap(long)]
+    db: Option<String>,
+}
+
+impl Runnable for HistoryCmd {
+    fn run(&self) {
+        let config = APP.config();
+        let path = match self.db.clone().map(PathBuf::from) {
+            Some(p) => p,
+            None => config.audit_db_path().unwrap_or_else(|| {
+                status_err!("neither audit.db_path nor keystore is set in the config");
+                std::process::exit(1);
+            }),
+        };
+        let filter = HistoryFilter {
+            cellar_id: self.cellar.clone(),
+            cork_id: self.cork_id.clone(),
+            tx_hash: self.tx_hash.clone(),
+            requester: self.requester.clone(),
+            min_height: self.min_height,
+            max_height: self.max_height,
+            limit: self.limit,
+        };
+
+        let records = audit::query(&path, &filter).unwrap_or_else(|err| {
+            status_err!("failed to query cork history: {}", err);
+            std::process::exit(1);
+        });
+
+        for record in records {
+            println!("{} {} {}", record.timestamp, record.source, record.cork_id);
+            println!("  cellar:    {}", record.cellar_id);
+            if !record.cellar_type.is_empty() {
+                println!("  type:      {}", record.cellar_type);
+            }
+            println!("  function:  {}", record.function);
+            println!("  arguments: {}", record.arguments);
+            println!("  requester: {}", record.requester);
+            if !record.requester_fingerprint.is_empty() {
+                println!("  sha256:    {}", record.requester_fingerprint);
+            }
+            if let Some(target_height) = record.target_height {
+                println!("  target:    {}", target_height);
+            }
+            println!(
+                "  tx:        {} at height {} with code {}",
+                record.tx_hash, record.height, record.code
+            );
+            println!("  result:    {}", record.result);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{application::APP, prelude::*};
+use crate::{application::APP, cosmos_endpoints, prelude::*};
 use abscissa_core::{clap::Parser, Command, Runnable};
 use ethers::prelude::{Middleware, Signer, SignerMiddleware};
 use ethers::types::Address as EthAddress;
@@ -64,7 +64,7 @@ impl Runnable for StartCommand {
         abscissa_tokio::run_with_actix(&APP, async {
             let connections = create_rpc_connections(
                 cosmos_prefix,
-                Some(config.cosmos.grpc.clone()),
+                Some(cosmos_endpoints::select().await),
                 Some(config.ethereum.rpc.clone()),
                 timeout,
             )
@@ -1,7 +1,16 @@
 mod aave2_clr_s;
+mod list;
 
 use crate::commands::schedule_corks::aave2_clr_s::AaveV2StablecoinCellarCmd;
+use crate::{
+    application::APP,
+    cellars,
+    cork::{self, height_estimate},
+    prelude::*,
+};
 use abscissa_core::{clap::Parser, Command, Runnable};
+use chrono::{DateTime, Utc};
+use std::io::Write;
 
 /// Schedule corks command
 #[derive(Command, Debug, Parser, Runnable)]
@@ -9,4 +18,138 @@ pub enum ScheduleCmd {
     /// Manage Aave cellar validator functions
     #[clap(name = "aave2-clr-s", subcommand)]
     AaveV2StablecoinCellar(AaveV2StablecoinCellarCmd),
+    /// List pending scheduled corks
+    List(list::ListCmd),
+}
+
+/// Target cellar and block height shared by the schedule subcommands
+#[derive(Debug, Parser)]
+pub struct ScheduleTarget {
+    /// Target contract for scheduled cork.
+    #[clap(short, long)]
+    contract: String,
+
+    /// Block height to schedule cork.
+    #[clap(
+        short = 'b',
+        long,
+        required_unless_present_any = &["at", "delay"],
+        conflicts_with_all = &["at", "delay"]
+    )]
+    height: Option<u64>,
+
+    /// Schedule cork for the block height estimated to be reached at this RFC 3339 time, e.g. 2022-06-01T16:00:00Z.
+    #[clap(long, conflicts_with = "delay")]
+    at: Option<String>,
+
+    /// Schedule cork for the block height estimated to be reached after this duration, e.g. 90m or 2h30m.
+    #[clap(long = "in")]
+    delay: Option<String>,
+
+    /// Schedule an estimated height without asking for confirmation.
+    #[clap(short, long)]
+    yes: bool,
+}
+
+impl ScheduleTarget {
+    /// Schedules `encoded_call` for the target cellar and height and prints the transaction
+    /// result, exiting the process on failure or if the transaction was rejected. `cellar_type`
+    /// names the adapter whose ABI the call was encoded with.
+    pub fn schedule(&self, cellar_type: &str, encoded_call: Vec<u8>) {
+        cellars::validate_cellar_id(self.contract.as_str()).unwrap_or_else(|err| {
+            status_err!("Can't validate contract address format: {}", err);
+            std::process::exit(1);
+        });
+
+        let response = abscissa_tokio::run_with_actix(&APP, async {
+            let height = self.get_height().await;
+
+            cork::schedule_cork(self.contract.clone(), cellar_type, encoded_call, height)
+                .await
+                .unwrap_or_else(|err| {
+                    status_err!("executor exited with error: {}", err);
+                    std::process::exit(1);
+                })
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            std::process::exit(1);
+        });
+
+        println!("tx hash: {}", response.txhash);
+        println!("code:    {}", response.code);
+        if !response.raw_log.is_empty() {
+            println!("log:     {}", response.raw_log);
+        }
+        if response.code != 0 {
+            status_err!("scheduling cork failed with code {}", response.code);
+            std::process::exit(1);
+        }
+    }
+
+    /// Returns `--height`, or the height estimated from `--at` or `--in` once confirmed
+    async fn get_height(&self) -> u64 {
+        if let Some(height) = self.height {
+            return height;
+        }
+
+        let target = match (&self.at, &self.delay) {
+            (Some(at), _) => DateTime::parse_from_rfc3339(at)
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or_else(|err| {
+                    status_err!("invalid time {}: {}", at, err);
+                    std::process::exit(1);
+                }),
+            (None, Some(delay)) => {
+                let delay = humantime::parse_duration(delay)
+                    .ok()
+                    .and_then(|d| chrono::Duration::from_std(d).ok())
+                    .unwrap_or_else(|| {
+                        status_err!("invalid duration {}", delay);
+                        std::process::exit(1);
+                    });
+                Utc::now() + delay
+            }
+            (None, None) => unreachable!("clap requires --height, --at or --in"),
+        };
+
+        let estimate = height_estimate::estimate(target)
+            .await
+            .unwrap_or_else(|err| {
+                status_err!("failed to estimate block height: {}", err);
+                std::process::exit(1);
+            });
+        println!("target time:      {}", target.to_rfc3339());
+        println!(
+            "latest block:     {} at {}",
+            estimate.latest_height,
+            estimate.latest_time.to_rfc3339()
+        );
+        println!("block time:       {:.2}s", estimate.block_time);
+        println!(
+            "estimated height: {} (margin {} blocks, about {:.0}s)",
+            estimate.height,
+            estimate.margin,
+            estimate.margin as f64 * estimate.block_time
+        );
+
+        if !self.yes && !confirm(&format!("Schedule cork for height {}?", estimate.height)) {
+            status_err!("aborted");
+            std::process::exit(1);
+        }
+
+        estimate.height
+    }
+}
+
+/// Asks a yes or no question on the terminal, defaulting to no
+fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    if std::io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
 }
@@ -1,6 +1,7 @@
 mod fees_distributor;
 mod initiate_shutdown;
 mod lift_shutdown;
+mod strategist;
 mod sweep;
 mod transfer_ownership;
 mod trust;
@@ -16,4 +17,14 @@ pub enum AaveV2StablecoinCellarCmd {
     Sweep(sweep::SweepCmd),
     Trust(trust::TrustCmd),
     TransferOwnership(transfer_ownership::TransferOwnershipCmd),
+    Accrue(strategist::AccrueCmd),
+    ClaimAndUnstake(strategist::ClaimAndUnstakeCmd),
+    EnterPosition(strategist::EnterPositionCmd),
+    ExitPosition(strategist::ExitPositionCmd),
+    Rebalance(strategist::RebalanceCmd),
+    Reinvest(strategist::ReinvestCmd),
+    SendFees(strategist::SendFeesCmd),
+    SetAccrualPeriod(strategist::SetAccrualPeriodCmd),
+    SetDepositLimit(strategist::SetDepositLimitCmd),
+    SetLiquidityLimit(strategist::SetLiquidityLimitCmd),
 }
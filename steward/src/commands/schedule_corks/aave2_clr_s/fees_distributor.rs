@@ -1,4 +1,4 @@
-use crate::{application::APP, cellars, cork, prelude::*};
+use crate::{cellars::aave_v2_stablecoin::CELLAR_NAME, commands::schedule_corks::ScheduleTarget};
 use abscissa_core::{clap::Parser, Command, Runnable};
 use deep_space::Address;
 use ethers::abi::AbiEncode;
@@ -14,48 +14,27 @@ pub struct FeesDistributorCmd {
     /// Fee distributor's address
     new_fees_distributor: Address,
 
-    /// Target contract for scheduled cork.
-    #[clap(short = 'c', long)]
-    contract: String,
-
-    /// Block height to schedule cork.
-    #[clap(short = 'b', long)]
-    height: u64,
+    #[clap(flatten)]
+    target: ScheduleTarget,
 }
 
 impl Runnable for FeesDistributorCmd {
     fn run(&self) {
-        abscissa_tokio::run_with_actix(&APP, async {
-            let mut address = self.new_fees_distributor.as_bytes().to_vec();
-
-            while address.len() < 32 {
-                address.insert(0, 0u8);
-            }
-
-            let mut address_slice: [u8; 32] = Default::default();
-            address_slice.copy_from_slice(&address[..]);
-
-            let call = SetFeesDistributorCall {
-                new_fees_distributor: address_slice,
-            };
-
-            let encoded_call = AaveV2StablecoinCellarCalls::SetFeesDistributor(call).encode();
-
-            cellars::validate_cellar_id(self.contract.as_str()).unwrap_or_else(|err| {
-                status_err!("Can't validate contract address format: {}", err);
-                std::process::exit(1);
-            });
-
-            cork::schedule_cork(self.contract.clone(), encoded_call, self.height)
-                .await
-                .unwrap_or_else(|err| {
-                    status_err!("executor exited with error: {}", err);
-                    std::process::exit(1);
-                })
-        })
-        .unwrap_or_else(|e| {
-            status_err!("executor exited with error: {}", e);
-            std::process::exit(1);
-        });
+        let mut address = self.new_fees_distributor.as_bytes().to_vec();
+
+        while address.len() < 32 {
+            address.insert(0, 0u8);
+        }
+
+        let mut address_slice: [u8; 32] = Default::default();
+        address_slice.copy_from_slice(&address[..]);
+
+        let call = SetFeesDistributorCall {
+            new_fees_distributor: address_slice,
+        };
+
+        let encoded_call = AaveV2StablecoinCellarCalls::SetFeesDistributor(call).encode();
+
+        self.target.schedule(CELLAR_NAME, encoded_call);
     }
 }
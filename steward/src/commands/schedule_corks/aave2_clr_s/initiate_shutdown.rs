@@ -1,4 +1,4 @@
-use crate::{application::APP, cellars, cork, prelude::*};
+use crate::{cellars::aave_v2_stablecoin::CELLAR_NAME, commands::schedule_corks::ScheduleTarget};
 use abscissa_core::{clap::Parser, Command, Runnable};
 use ethers::abi::AbiEncode;
 use steward_abi::aave_v2_stablecoin::*;
@@ -9,13 +9,8 @@ use steward_abi::aave_v2_stablecoin::*;
     long_about = "DESCRIPTION \n\n Initiate shutdown of target cellar when chain reaches specified height"
 )]
 pub struct InitiateShutdownCmd {
-    /// Target contract for scheduled cork.
-    #[clap(short, long)]
-    contract: String,
-
-    /// Block height to schedule cork.
-    #[clap(short = 'b', long)]
-    height: u64,
+    #[clap(flatten)]
+    target: ScheduleTarget,
 
     /// Set to true if you want to exit current position.
     #[clap(short = 'e', long)]
@@ -24,27 +19,11 @@ pub struct InitiateShutdownCmd {
 
 impl Runnable for InitiateShutdownCmd {
     fn run(&self) {
-        abscissa_tokio::run_with_actix(&APP, async {
-            let call = InitiateShutdownCall {
-                empty_position: self.empty_position,
-            };
-            let encoded_call = AaveV2StablecoinCellarCalls::InitiateShutdown(call).encode();
-
-            cellars::validate_cellar_id(self.contract.as_str()).unwrap_or_else(|err| {
-                status_err!("Can't validate contract address format: {}", err);
-                std::process::exit(1);
-            });
+        let call = InitiateShutdownCall {
+            empty_position: self.empty_position,
+        };
+        let encoded_call = AaveV2StablecoinCellarCalls::InitiateShutdown(call).encode();
 
-            cork::schedule_cork(self.contract.clone(), encoded_call, self.height)
-                .await
-                .unwrap_or_else(|err| {
-                    status_err!("executor exited with error: {}", err);
-                    std::process::exit(1);
-                })
-        })
-        .unwrap_or_else(|e| {
-            status_err!("executor exited with error: {}", e);
-            std::process::exit(1);
-        });
+        self.target.schedule(CELLAR_NAME, encoded_call);
     }
 }
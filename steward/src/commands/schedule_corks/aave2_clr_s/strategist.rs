@@ -0,0 +1,300 @@
+//! Subcommands for the strategist functions that strategy providers call through the `Submit` RPC.
+//! Each builds the same call data as a `Submit` request and is encoded by the cellar adapter.
+
+use crate::{
+    cellars::aave_v2_stablecoin::{get_encoded_call, CELLAR_NAME},
+    commands::schedule_corks::ScheduleTarget,
+    prelude::*,
+};
+use abscissa_core::{clap::Parser, Command, Runnable};
+use steward_proto::steward::aave_v2_stablecoin::{
+    rebalance::SwapParams, Accrue, ClaimAndUnstake, EnterPosition, EnterPositionWithAssets,
+    ExitPosition, ExitPositionWithAssets, Function, Rebalance, Reinvest, SendFees,
+    SetAccrualPeriod, SetDepositLimit, SetLiquidityLimit,
+};
+
+/// Number of addresses in a rebalance route
+const ROUTE_LENGTH: usize = 9;
+/// Number of swap parameter triples in a rebalance
+const SWAP_PARAMS_LENGTH: usize = 4;
+
+fn schedule(target: &ScheduleTarget, function: Function) {
+    let encoded_call = get_encoded_call(function).unwrap_or_else(|err| {
+        status_err!("failed to encode call: {}", err);
+        std::process::exit(1);
+    });
+
+    target.schedule(CELLAR_NAME, encoded_call);
+}
+
+/// Accrue subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Accrue yield, platform fees, and performance fees of target cellar when chain reaches specified height"
+)]
+pub struct AccrueCmd {
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for AccrueCmd {
+    fn run(&self) {
+        schedule(&self.target, Function::Accrue(Accrue {}));
+    }
+}
+
+/// Claim and unstake subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Claim rewards from Aave and begin cooldown period to unstake them when chain reaches specified height"
+)]
+pub struct ClaimAndUnstakeCmd {
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for ClaimAndUnstakeCmd {
+    fn run(&self) {
+        schedule(&self.target, Function::ClaimAndUnstake(ClaimAndUnstake {}));
+    }
+}
+
+/// Enter position subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Deposit assets of target cellar into its current lending position when chain reaches specified height"
+)]
+pub struct EnterPositionCmd {
+    /// Amount of assets to deposit. Deposits all holdings if omitted.
+    #[clap(short, long)]
+    assets: Option<String>,
+
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for EnterPositionCmd {
+    fn run(&self) {
+        let function = match &self.assets {
+            Some(assets) => Function::EnterPositionWithAssets(EnterPositionWithAssets {
+                assets: assets.clone(),
+            }),
+            None => Function::EnterPosition(EnterPosition {}),
+        };
+
+        schedule(&self.target, function);
+    }
+}
+
+/// Exit position subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Withdraw assets of target cellar from its current lending position when chain reaches specified height"
+)]
+pub struct ExitPositionCmd {
+    /// Amount of assets to withdraw. Withdraws the entire position if omitted.
+    #[clap(short, long)]
+    assets: Option<String>,
+
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for ExitPositionCmd {
+    fn run(&self) {
+        let function = match &self.assets {
+            Some(assets) => Function::ExitPositionWithAssets(ExitPositionWithAssets {
+                assets: assets.clone(),
+            }),
+            None => Function::ExitPosition(ExitPosition {}),
+        };
+
+        schedule(&self.target, function);
+    }
+}
+
+/// Rebalance subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Rebalance target cellar into a new lending position through a Curve swap when chain reaches specified height.\n The route is padded to 9 addresses with the zero address and the swap params to 4 entries with 0:0:0."
+)]
+pub struct RebalanceCmd {
+    /// Curve swap route addresses, comma separated or repeated.
+    #[clap(short, long, use_delimiter = true, required = true)]
+    route: Vec<String>,
+
+    /// Swap parameters for each pool in the route as <in_index>:<out_index>:<swap_type>.
+    #[clap(short, long)]
+    swap_params: Vec<String>,
+
+    /// Minimum amount of assets received from the swap.
+    #[clap(short, long)]
+    min_assets_out: String,
+
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for RebalanceCmd {
+    fn run(&self) {
+        if self.route.len() > ROUTE_LENGTH || self.swap_params.len() > SWAP_PARAMS_LENGTH {
+            status_err!(
+                "a route has at most {} addresses and {} swap params",
+                ROUTE_LENGTH,
+                SWAP_PARAMS_LENGTH
+            );
+            std::process::exit(1);
+        }
+
+        let mut route = self.route.clone();
+        route.resize(ROUTE_LENGTH, format!("{:?}", ethers::types::H160::zero()));
+        let mut swap_params = self
+            .swap_params
+            .iter()
+            .map(|p| parse_swap_params(p))
+            .collect::<Vec<SwapParams>>();
+        swap_params.resize(SWAP_PARAMS_LENGTH, SwapParams::default());
+
+        let function = Function::Rebalance(Rebalance {
+            route,
+            swap_params,
+            min_assets_out: self.min_assets_out.clone(),
+        });
+
+        schedule(&self.target, function);
+    }
+}
+
+/// Parses `<in_index>:<out_index>:<swap_type>`, exiting the process if it is malformed
+fn parse_swap_params(value: &str) -> SwapParams {
+    let parts = value
+        .split(':')
+        .map(|p| p.trim().parse::<u64>())
+        .collect::<Result<Vec<u64>, _>>();
+    match parts.as_deref() {
+        Ok([in_index, out_index, swap_type]) => SwapParams {
+            in_index: *in_index,
+            out_index: *out_index,
+            swap_type: *swap_type,
+        },
+        _ => {
+            status_err!(
+                "invalid swap params {}, expected <in_index>:<out_index>:<swap_type>",
+                value
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reinvest subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Swap unstaked rewards of target cellar for its asset and deposit them into its current position when chain reaches specified height"
+)]
+pub struct ReinvestCmd {
+    /// Minimum amount of assets received from the swap.
+    #[clap(short, long)]
+    min_assets_out: String,
+
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for ReinvestCmd {
+    fn run(&self) {
+        let function = Function::Reinvest(Reinvest {
+            min_assets_out: self.min_assets_out.clone(),
+        });
+
+        schedule(&self.target, function);
+    }
+}
+
+/// Send fees subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Transfer accrued fees of target cellar to Cosmos to be distributed when chain reaches specified height"
+)]
+pub struct SendFeesCmd {
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for SendFeesCmd {
+    fn run(&self) {
+        schedule(&self.target, Function::SendFees(SendFees {}));
+    }
+}
+
+/// Set accrual period subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Set the period over which yield of target cellar is distributed when chain reaches specified height"
+)]
+pub struct SetAccrualPeriodCmd {
+    /// New accrual period in seconds.
+    #[clap(short, long)]
+    period: u32,
+
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for SetAccrualPeriodCmd {
+    fn run(&self) {
+        let function = Function::SetAccrualPeriod(SetAccrualPeriod {
+            new_accrual_period: self.period,
+        });
+
+        schedule(&self.target, function);
+    }
+}
+
+/// Set deposit limit subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Set the maximum amount of assets a single user may deposit into target cellar when chain reaches specified height"
+)]
+pub struct SetDepositLimitCmd {
+    /// New limit in the smallest unit of the cellar's asset.
+    #[clap(short, long)]
+    limit: String,
+
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for SetDepositLimitCmd {
+    fn run(&self) {
+        let function = Function::SetDepositLimit(SetDepositLimit {
+            limit: self.limit.clone(),
+        });
+
+        schedule(&self.target, function);
+    }
+}
+
+/// Set liquidity limit subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n Set the maximum amount of assets target cellar may hold when chain reaches specified height"
+)]
+pub struct SetLiquidityLimitCmd {
+    /// New limit in the smallest unit of the cellar's asset.
+    #[clap(short, long)]
+    limit: String,
+
+    #[clap(flatten)]
+    target: ScheduleTarget,
+}
+
+impl Runnable for SetLiquidityLimitCmd {
+    fn run(&self) {
+        let function = Function::SetLiquidityLimit(SetLiquidityLimit {
+            limit: self.limit.clone(),
+        });
+
+        schedule(&self.target, function);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{application::APP, cellars, cork, prelude::*};
+use crate::{cellars::aave_v2_stablecoin::CELLAR_NAME, commands::schedule_corks::ScheduleTarget};
 use abscissa_core::{clap::Parser, Command, Runnable};
 use ethers::abi::AbiEncode;
 use ethers::types::*;
@@ -19,41 +19,20 @@ pub struct TrustCmd {
     #[clap(short = 't', long)]
     trust: bool,
 
-    /// Block height to schedule cork.
-    #[clap(short = 'b', long)]
-    height: u64,
-
-    /// Target contract for scheduled cork.
-    #[clap(short = 'c', long)]
-    contract: String,
+    #[clap(flatten)]
+    target: ScheduleTarget,
 }
 
 impl Runnable for TrustCmd {
     fn run(&self) {
-        abscissa_tokio::run_with_actix(&APP, async {
-            // Encoded call for trust
-            let call = SetTrustCall {
-                position: self.position,
-                trust: self.trust,
-            };
-
-            let encoded_call = AaveV2StablecoinCellarCalls::SetTrust(call).encode();
+        // Encoded call for trust
+        let call = SetTrustCall {
+            position: self.position,
+            trust: self.trust,
+        };
 
-            cellars::validate_cellar_id(self.contract.as_str()).unwrap_or_else(|err| {
-                status_err!("Can't validate contract address format: {}", err);
-                std::process::exit(1);
-            });
+        let encoded_call = AaveV2StablecoinCellarCalls::SetTrust(call).encode();
 
-            cork::schedule_cork(self.contract.clone(), encoded_call, self.height)
-                .await
-                .unwrap_or_else(|err| {
-                    status_err!("executor exited with error: {}", err);
-                    std::process::exit(1);
-                })
-        })
-        .unwrap_or_else(|e| {
-            status_err!("executor exited with error: {}", e);
-            std::process::exit(1);
-        });
+        self.target.schedule(CELLAR_NAME, encoded_call);
     }
 }
@@ -0,0 +1,42 @@
+use crate::{application::APP, cork::scheduled, prelude::*};
+use abscissa_core::{clap::Parser, Command, Runnable};
+
+/// List scheduled corks subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "DESCRIPTION \n\n List corks scheduled by this Steward's validator that the cork module has not yet submitted.\n Each contract call is decoded into the cellar function and arguments it invokes."
+)]
+pub struct ListCmd {
+    /// Only show corks for this cellar.
+    #[clap(long)]
+    cellar: Option<String>,
+}
+
+impl Runnable for ListCmd {
+    fn run(&self) {
+        abscissa_tokio::run_with_actix(&APP, async {
+            let corks = scheduled::list(self.cellar.as_deref())
+                .await
+                .unwrap_or_else(|err| {
+                    status_err!("failed to query scheduled corks: {}", err);
+                    std::process::exit(1);
+                });
+
+            for cork in corks {
+                println!("{} {}", cork.block_height, cork.cork_id);
+                println!("  cellar:    {}", cork.cellar_id);
+                match cork.call {
+                    Some(call) => {
+                        println!("  function:  {}", call.signature);
+                        println!("  arguments: {}", call.arguments_string());
+                    }
+                    None => println!("  call:      0x{}", hex::encode(&cork.encoded_call)),
+                }
+            }
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            std::process::exit(1);
+        });
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{application::APP, prelude::*};
+use crate::{application::APP, cosmos_endpoints, prelude::*};
 use abscissa_core::{clap::Parser, Application, Command, Runnable};
 use gravity_bridge::gravity_proto::gravity as proto;
 use std::time::Duration;
@@ -28,7 +28,7 @@ impl Runnable for SignDelegateKeysCmd {
                 None => {
                     let timeout = Duration::from_secs(10);
                     let contact = deep_space::Contact::new(
-                        &config.cosmos.grpc,
+                        &cosmos_endpoints::select().await,
                         timeout,
                         &config.cosmos.prefix,
                     )
@@ -2,10 +2,21 @@
 
 /// App-local prelude includes `app_reader()`/`app_writer()`/`app_config()`
 /// accessors along with logging macros. Customize as you see fit.
-use crate::{application::APP, config::StewardConfig, cork::CorkHandler, prelude::*, server};
+use crate::{
+    application::APP,
+    config::StewardConfig,
+    cork::{cellar_ids, CorkHandler},
+    cosmos_endpoints, health, metrics,
+    prelude::*,
+    server::{self, rate_limit::ClientRateLimitLayer, revocation},
+};
 use abscissa_core::{clap::Parser, config, Command, FrameworkError, Runnable};
-use std::result::Result;
+use futures::StreamExt;
+use rustls::Session;
+use std::{io, result::Result};
 use steward_proto::steward::contract_call_server::ContractCallServer;
+use tonic_health::ServingStatus;
+use tower::ServiceBuilder;
 
 /// Cosmos Signer, start allocation module
 #[derive(Command, Debug, Parser)]
@@ -30,27 +41,74 @@ impl Runnable for StartCmd {
                     std::process::exit(1)
                 });
 
-            let server_config = server::load_server_config(&config)
+            let server_config = server::load_server_config(&config).unwrap_or_else(|err| {
+                status_err!("failed to load server config: {}", err);
+                std::process::exit(1)
+            });
+            let incoming = server_config
+                .tls
+                .incoming(server_config.address)
                 .await
                 .unwrap_or_else(|err| {
-                    status_err!("failed to load server config: {}", err);
+                    status_err!("failed to listen on {}: {}", server_config.address, err);
                     std::process::exit(1)
                 });
+            tokio::spawn(server_config.tls.clone().reload_loop());
+
+            let metrics_addr = config.metrics.listen_addr;
+            tokio::spawn(async move {
+                if let Err(err) = metrics::serve(metrics_addr).await {
+                    error!("metrics server error: {}", err);
+                }
+            });
+            tokio::spawn(metrics::refresh_delegate_balance());
+            tokio::spawn(cosmos_endpoints::health_check_loop());
+            tokio::spawn(cellar_ids::refresh_loop());
+
+            let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
+            health::set_status(&mut health_reporter, ServingStatus::NotServing).await;
+            let delegate_address = health::load_delegate_address();
+            tokio::spawn(health::report_loop(health_reporter, delegate_address));
 
             info!("listening on {}", server_config.address);
-            if let Err(err) = tonic::transport::Server::builder()
-                .tls_config(server_config.tls_config)
-                .unwrap_or_else(|err| {
-                    panic!("{:?}", err);
-                })
-                .add_service(ContractCallServer::new(CorkHandler))
-                .add_service(proto_descriptor_service)
-                .serve(server_config.address)
-                .await
-            {
-                status_err!("server error: {}", err);
-                std::process::exit(1)
+            // Each connection gets its own server so the client rate limit layer knows which
+            // certificate the requests on it were sent with
+            futures::pin_mut!(incoming);
+            while let Some(stream) = incoming.next().await {
+                let stream = match stream {
+                    Ok(s) => s,
+                    Err(err) => {
+                        error!("failed to accept connection: {}", err);
+                        continue;
+                    }
+                };
+                let client = stream
+                    .get_ref()
+                    .1
+                    .get_peer_certificates()
+                    .and_then(|certs| certs.first().map(|c| c.0.clone()))
+                    .map(|der| server::get_certificate_identity(&der).fingerprint)
+                    .unwrap_or_default();
+                let contract_call_service = ServiceBuilder::new()
+                    .layer(ClientRateLimitLayer::new(client))
+                    .service(ContractCallServer::with_interceptor(
+                        CorkHandler,
+                        revocation::intercept,
+                    ));
+                let router = tonic::transport::Server::builder()
+                    .add_service(contract_call_service)
+                    .add_service(proto_descriptor_service.clone())
+                    .add_service(health_service.clone());
+                tokio::spawn(async move {
+                    let connection = futures::stream::iter(vec![Ok::<_, io::Error>(stream)]);
+                    if let Err(err) = router.serve_with_incoming(connection).await {
+                        error!("server error: {}", err);
+                    }
+                });
             }
+
+            status_err!("stopped accepting connections");
+            std::process::exit(1)
         })
         .unwrap_or_else(|e| {
             status_err!("executor exited with error: {}", e);
@@ -3,7 +3,10 @@
 //! See instructions in `commands.rs` to specify the path to your
 //! application's configuration file and/or command-line options
 //! for specifying it.
-use crate::prelude::APP;
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
 use abscissa_core::Application;
 use deep_space::{Address, PrivateKey};
 use ethers::signers::LocalWallet as EthWallet;
@@ -11,7 +14,10 @@ use gravity_bridge::cosmos_gravity;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use signatory::FsKeyStore;
-use std::{net::SocketAddr, path::Path};
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+};
 
 lazy_static! {
     pub static ref DELEGATE_KEY: PrivateKey = {
@@ -32,6 +38,8 @@ lazy_static! {
 #[serde(default, deny_unknown_fields)]
 pub struct StewardConfig {
     pub keystore: String,
+    pub audit: AuditSection,
+    pub cork: CorkSection,
     pub cosmos: CosmosSection,
     pub ethereum: EthereumSection,
     pub gravity: GravitySection,
@@ -49,6 +57,26 @@ impl StewardConfig {
         key.to_pem().parse().expect("Could not parse pem")
     }
 
+    /// Loads a key like `load_deep_space_key`, returning an error instead of panicking if it is
+    /// missing or malformed
+    pub fn try_load_deep_space_key(&self, name: String) -> Result<PrivateKey, Error> {
+        let keystore = FsKeyStore::create_or_open(Path::new(&self.keystore))
+            .map_err(|e| ErrorKind::KeysError.context(format!("could not open keystore: {}", e)))?;
+        let key_name = name
+            .parse()
+            .map_err(|e| ErrorKind::KeysError.context(format!("could not parse name: {}", e)))?;
+        let key = keystore
+            .load(&key_name)
+            .map_err(|e| ErrorKind::KeysError.context(format!("could not load key: {}", e)))?;
+        let key: k256::elliptic_curve::SecretKey<k256::Secp256k1> = key
+            .to_pem()
+            .parse()
+            .map_err(|e| ErrorKind::KeysError.context(format!("could not parse pem: {}", e)))?;
+        let key = deep_space::utils::bytes_to_hex_str(&key.to_bytes());
+
+        Ok(key.parse()?)
+    }
+
     pub fn load_clarity_key(&self, name: String) -> clarity::PrivateKey {
         let key = self.load_secret_key(name).to_bytes();
         clarity::PrivateKey::from_slice(&key).expect("Could not convert key")
@@ -69,6 +97,19 @@ impl StewardConfig {
     pub fn load_ethers_wallet(&self, name: String) -> EthWallet {
         EthWallet::from(self.load_secret_key(name))
     }
+
+    /// Path of the audit database, `audit.db_path` or `steward_audit.sqlite` in the keystore
+    /// directory if it is unset. `None` if neither is configured.
+    pub fn audit_db_path(&self) -> Option<PathBuf> {
+        if !self.audit.db_path.is_empty() {
+            return Some(PathBuf::from(&self.audit.db_path));
+        }
+        if !self.keystore.is_empty() {
+            return Some(Path::new(&self.keystore).join("steward_audit.sqlite"));
+        }
+
+        None
+    }
 }
 
 /// Default configuration settings.
@@ -79,6 +120,8 @@ impl Default for StewardConfig {
     fn default() -> Self {
         Self {
             keystore: String::new(),
+            audit: AuditSection::default(),
+            cork: CorkSection::default(),
             cosmos: CosmosSection::default(),
             ethereum: EthereumSection::default(),
             gravity: GravitySection::default(),
@@ -93,20 +136,109 @@ impl Default for StewardConfig {
 #[serde(default)]
 pub struct ServerSection {
     pub address: String,
+    pub authorization: AuthorizationSection,
     pub client_ca_cert_path: Option<String>,
+    pub client_ca_cert_paths: Vec<String>,
     pub port: u16,
+    pub rate_limit: RateLimitSection,
+    pub revocation_dir: Option<String>,
     pub server_cert_path: String,
     pub server_key_path: String,
+    pub tls_reload_interval: u64,
 }
 
 impl Default for ServerSection {
     fn default() -> Self {
         Self {
             address: "0.0.0.0".to_string(),
+            authorization: AuthorizationSection::default(),
             client_ca_cert_path: None,
+            client_ca_cert_paths: Vec::new(),
             port: 5734,
+            rate_limit: RateLimitSection::default(),
+            revocation_dir: None,
             server_cert_path: "".to_owned(),
             server_key_path: "".to_owned(),
+            tls_reload_interval: 10,
+        }
+    }
+}
+
+/// Limits on the rate of `ContractCall` requests, disabled by default
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitSection {
+    /// Limit for each client certificate
+    pub client: RateLimit,
+    /// Limit for each cellar ID, across all clients
+    pub cellar: RateLimit,
+}
+
+/// A token bucket limit. A `requests_per_minute` of zero disables the limit.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimit {
+    pub requests_per_minute: u32,
+    pub burst: u32,
+}
+
+/// Restricts which cellars and functions each client may submit corks for. With no policies every
+/// client trusted by the client CA may submit for any cellar.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthorizationSection {
+    pub policies: Vec<AuthorizationPolicy>,
+}
+
+/// Grants the clients matching `subjects` or `fingerprints` access to `functions` on `cellar_ids`.
+/// `"*"` in `cellar_ids` or `functions` matches any value.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthorizationPolicy {
+    pub subjects: Vec<String>,
+    pub fingerprints: Vec<String>,
+    pub cellar_ids: Vec<String>,
+    pub functions: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuditSection {
+    pub enabled: bool,
+    pub db_path: String,
+}
+
+impl Default for AuditSection {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            db_path: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorkSection {
+    pub batch_window_ms: u64,
+    pub cellar_ids_max_staleness: u64,
+    pub cellar_ids_refresh_interval: u64,
+    pub dedup_window: u64,
+    pub idempotency_key_ttl: u64,
+    pub preflight: bool,
+    pub schedule_horizon: u64,
+}
+
+impl Default for CorkSection {
+    fn default() -> Self {
+        Self {
+            batch_window_ms: 0,
+            cellar_ids_max_staleness: 300,
+            cellar_ids_refresh_interval: 30,
+            dedup_window: 60,
+            idempotency_key_ttl: 86400,
+            preflight: false,
+            schedule_horizon: 50000,
         }
     }
 }
@@ -172,17 +304,27 @@ pub struct CosmosSection {
     pub prefix: String,
     pub msg_batch_size: u32,
     pub gas_price: GasPrice,
+    pub grpc_endpoints: Vec<String>,
+    pub endpoint_health_check_interval: u64,
+    pub max_block_age: u64,
+    pub fee_balance_floor: u64,
+    pub fee_balance_warning_threshold: u64,
 }
 
 impl Default for CosmosSection {
     fn default() -> Self {
         Self {
-            gas_adjustment: 1.0f64,
+            gas_adjustment: 1.3f64,
             grpc: "https://127.0.0.1:9090".to_owned(),
             key_derivation_path: "m/44'/118'/0'/0/0".to_owned(),
             prefix: "somm".to_owned(),
             msg_batch_size: 5,
             gas_price: GasPrice::default(),
+            grpc_endpoints: Vec::new(),
+            endpoint_health_check_interval: 15,
+            max_block_age: 60,
+            fee_balance_floor: 0,
+            fee_balance_warning_threshold: 0,
         }
     }
 }
@@ -1,24 +1,51 @@
 use crate::{
-    cellars::{self, aave_v2_stablecoin},
-    config,
+    audit::{self, CorkRecord, CorkSource},
+    cellars::{self, CellarAdapter},
+    config, cosmos_endpoints,
     error::{Error, ErrorKind},
+    metrics,
     prelude::APP,
-    somm_send,
+    server::{self, authorization, rate_limit::CELLAR_LIMITER, ClientIdentity},
+    somm_send, utils,
 };
 use abscissa_core::{
     tracing::log::{debug, error, info, warn},
     Application,
 };
-use deep_space::{Coin, Contact};
-use gravity_bridge::gravity_proto::cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
-use somm_proto::cork::{query_client::QueryClient as CorkQueryClient, Cork, QueryCellarIDsRequest};
+use deep_space::{client::ChainStatus, Contact};
+use ethers::{
+    prelude::{HttpClientError, JsonRpcError, Middleware, ProviderError, TransactionRequest},
+    types::H160,
+    utils::keccak256,
+};
+use gravity_bridge::gravity_proto::{
+    cosmos_sdk_proto::cosmos::{
+        base::{abci::v1beta1::TxResponse, query::v1beta1::PageRequest},
+        tx::v1beta1::{service_client::ServiceClient as TxServiceClient, GetTxRequest},
+    },
+    gravity::{query_client::QueryClient as GravityQueryClient, ContractCallTxsRequest},
+};
+use somm_proto::cork::{
+    query_client::QueryClient as CorkQueryClient, Cork, QuerySubmittedCorksRequest,
+};
 use std::time::Duration;
 use steward_proto::{
     self,
-    steward::{self, submit_request::CallData::AaveV2Stablecoin, SubmitRequest, SubmitResponse},
+    steward::{
+        self, CorkStatus, GetCorkStatusRequest, GetCorkStatusResponse, ListScheduledCorksRequest,
+        ListScheduledCorksResponse, ScheduleRequest, ScheduleResponse, SimulateResponse,
+        SubmitRequest, SubmitResponse,
+    },
 };
 use tonic::{self, async_trait, Code, Request, Response, Status};
 
+pub mod batch;
+pub mod cellar_ids;
+pub mod dedup;
+pub mod fee_balance;
+pub mod height_estimate;
+pub mod scheduled;
+
 const MESSAGE_TIMEOUT: Duration = Duration::from_secs(10);
 const CHAIN_PREFIX: &str = "somm";
 
@@ -30,44 +57,63 @@ impl steward::contract_call_server::ContractCall for CorkHandler {
         &self,
         request: Request<SubmitRequest>,
     ) -> Result<Response<SubmitResponse>, Status> {
-        let request = request.get_ref().to_owned();
+        let cellar_id = request.get_ref().cellar_id.clone();
+        let function = get_function_label(request.get_ref());
+        let result = submit_cork(request).await;
 
-        // Check if cellar is governance approved before building cork
-        let config = APP.config();
-        let mut client = match CorkQueryClient::connect(config.cosmos.grpc.clone()).await {
-            Ok(c) => c,
-            Err(err) => {
-                error!("cork query client connection failed: {}", err);
+        let code = match &result {
+            Ok(_) => Code::Ok,
+            Err(status) => status.code(),
+        };
+        metrics::SUBMIT_REQUESTS
+            .with_label_values(&[
+                metrics::cellar_label(&cellar_id),
+                &function,
+                &format!("{:?}", code),
+            ])
+            .inc();
+
+        result
+    }
+
+    async fn get_cork_status(
+        &self,
+        request: Request<GetCorkStatusRequest>,
+    ) -> Result<Response<GetCorkStatusResponse>, Status> {
+        let request = request.get_ref().to_owned();
+        let cork_id = match hex::decode(request.cork_id.trim_start_matches("0x")) {
+            Ok(id) if id.len() == 32 => id,
+            _ => {
                 return Err(Status::new(
-                    Code::Internal,
-                    format!("failed to query chain to validate cellar id: {}", err),
-                ));
+                    Code::InvalidArgument,
+                    format!("invalid cork ID {}", request.cork_id),
+                ))
             }
         };
 
-        debug!("checking if cellar ID is approved");
-        let ids = &client
-            .query_cellar_i_ds(QueryCellarIDsRequest {})
-            .await?
-            .get_ref()
-            .cellar_ids
-            .clone();
-        if !ids.contains(&request.cellar_id) {
-            info!(
-                "rejecting request for unapproved cellar {}",
-                request.cellar_id
-            );
-            return Err(Status::new(
-                Code::PermissionDenied,
-                format!(
-                    "cellar ID {} not approved by governance",
-                    &request.cellar_id
-                ),
-            ));
+        match get_cork_status(cork_id, request.tx_hash).await {
+            Ok(response) => Ok(Response::new(response)),
+            Err(err) => {
+                error!("failed to query cork status: {}", err);
+                Err(Status::new(
+                    Code::Internal,
+                    format!("failed to query cork status: {}", err),
+                ))
+            }
         }
+    }
 
-        // Build and send cork
+    async fn simulate(
+        &self,
+        request: Request<SubmitRequest>,
+    ) -> Result<Response<SimulateResponse>, Status> {
+        let request = request.get_ref().to_owned();
         let cellar_id = request.cellar_id.clone();
+        let adapter = match cellars::get_adapter(&request) {
+            Ok(a) => a,
+            Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+        };
+
         let cork = match build_cork(request).await {
             Ok(c) => c,
             Err(err) => {
@@ -75,100 +121,797 @@ impl steward::contract_call_server::ContractCall for CorkHandler {
                 return Err(Status::new(Code::InvalidArgument, err.to_string()));
             }
         };
-        debug!("cork: {:?}", cork);
+        let cork_id = match get_cork_id(&cork) {
+            Ok(id) => hex::encode(id),
+            Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+        };
+        let function_signature =
+            match cellars::get_function_signature(adapter.abi(), &cork.encoded_contract_call) {
+                Ok(s) => s,
+                Err(err) => return Err(Status::new(Code::Internal, err.to_string())),
+            };
+        debug!(
+            "simulated cork {} for {}: {}",
+            cork_id, cellar_id, function_signature
+        );
+
+        Ok(Response::new(SimulateResponse {
+            cork_id,
+            target_contract_address: cork.target_contract_address,
+            encoded_contract_call: cork.encoded_contract_call,
+            function_signature,
+        }))
+    }
+
+    async fn schedule(
+        &self,
+        request: Request<ScheduleRequest>,
+    ) -> Result<Response<ScheduleResponse>, Status> {
+        let submit = request.get_ref().request.clone().unwrap_or_default();
+        let function = get_function_label(&submit);
+        let result = schedule_request(request).await;
+
+        let code = match &result {
+            Ok(_) => Code::Ok,
+            Err(status) => status.code(),
+        };
+        metrics::SCHEDULE_REQUESTS
+            .with_label_values(&[
+                metrics::cellar_label(&submit.cellar_id),
+                &function,
+                &format!("{:?}", code),
+            ])
+            .inc();
+
+        result
+    }
+
+    async fn list_scheduled_corks(
+        &self,
+        request: Request<ListScheduledCorksRequest>,
+    ) -> Result<Response<ListScheduledCorksResponse>, Status> {
+        let config = APP.config();
+        let identity = server::get_client_identity(&request);
+        let policies = &config.server.authorization.policies;
+        if let Err(reason) = authorization::authorize_listing(policies, identity.as_ref()) {
+            info!("rejecting unauthorized request: {}", reason);
+            return Err(Status::new(Code::PermissionDenied, reason));
+        }
+        let cellar_id = request.into_inner().cellar_id;
+        if !cellar_id.is_empty()
+            && !authorization::may_view_cellar(policies, identity.as_ref(), &cellar_id)
+        {
+            return Err(Status::new(
+                Code::PermissionDenied,
+                format!("client is not authorized for cellar {}", cellar_id),
+            ));
+        }
+
+        let cellar_id = Some(cellar_id.as_str()).filter(|id| !id.is_empty());
+        let corks = match scheduled::list(cellar_id).await {
+            // Only corks for cellars the client is authorized for are listed
+            Ok(c) => c
+                .into_iter()
+                .filter(|c| {
+                    authorization::may_view_cellar(policies, identity.as_ref(), &c.cellar_id)
+                })
+                .collect::<Vec<_>>(),
+            Err(err) => {
+                error!("failed to query scheduled corks: {}", err);
+                return Err(Status::new(
+                    Code::Internal,
+                    format!("failed to query scheduled corks: {}", err),
+                ));
+            }
+        };
+
+        Ok(Response::new(ListScheduledCorksResponse {
+            corks: corks
+                .into_iter()
+                .map(|c| steward::ScheduledCork {
+                    function_signature: c
+                        .call
+                        .as_ref()
+                        .map(|d| d.signature.clone())
+                        .unwrap_or_default(),
+                    arguments: c
+                        .call
+                        .as_ref()
+                        .map(|d| d.arguments_string())
+                        .unwrap_or_default(),
+                    cork_id: c.cork_id,
+                    cellar_id: c.cellar_id,
+                    block_height: c.block_height,
+                    encoded_contract_call: c.encoded_call,
+                })
+                .collect(),
+        }))
+    }
+}
+
+/// Validates, builds and sends the cork for a `Submit` request
+async fn submit_cork(request: Request<SubmitRequest>) -> Result<Response<SubmitResponse>, Status> {
+    let identity = server::get_client_identity(&request);
+    let request = request.get_ref().to_owned();
+    let adapter = check_request(CorkSource::Submit, identity.as_ref(), &request).await?;
+
+    // Build and send cork
+    let cellar_id = request.cellar_id.clone();
+    let cork = match build_cork(request.clone()).await {
+        Ok(c) => c,
+        Err(err) => {
+            warn!("failed to build cork for cellar {}: {}", cellar_id, err);
+            metrics::ENCODING_FAILURES
+                .with_label_values(&[metrics::cellar_label(&cellar_id)])
+                .inc();
+            let mut record = rejection_record(CorkSource::Submit, identity.as_ref(), &request);
+            record.result = format!("rejected: {}", err);
+            audit::record(record).await;
+            return Err(Status::new(Code::InvalidArgument, err.to_string()));
+        }
+    };
+    let identity = identity.unwrap_or_default();
+    debug!("cork: {:?}", cork);
 
-        if let Err(err) = send_cork(cork).await {
+    let cork_id = match get_cork_id(&cork) {
+        Ok(id) => hex::encode(id),
+        Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+    };
+    let send = send_submitted_cork(cork, cork_id.clone(), identity.clone(), adapter);
+    match dedup::get_key(&identity.fingerprint, &request.idempotency_key, &cork_id) {
+        Some((key, ttl)) => dedup::run(key, ttl, send).await,
+        None => send.await.0,
+    }
+}
+
+/// Preflights and sends the cork built for a `Submit` request and records it in the audit log.
+/// Also returns whether a transaction may have been broadcast for it.
+async fn send_submitted_cork(
+    cork: Cork,
+    cork_id: String,
+    identity: ClientIdentity,
+    adapter: &'static dyn CellarAdapter,
+) -> (Result<Response<SubmitResponse>, Status>, bool) {
+    let config = APP.config();
+    let cellar_id = cork.target_contract_address.clone();
+    let mut record = CorkRecord::new(
+        CorkSource::Submit,
+        identity.subject,
+        identity.fingerprint,
+        cellar_id.clone(),
+        adapter.name(),
+        &cork.encoded_contract_call,
+    );
+    record.cork_id = cork_id.clone();
+    if config.cork.preflight {
+        debug!("performing preflight call of cork {}", cork_id);
+        match preflight_cork(&cork, adapter.abi_json()).await {
+            Ok(None) => (),
+            Ok(Some(reason)) => {
+                info!(
+                    "rejecting cork {} for {} which would revert: {}",
+                    cork_id, cellar_id, reason
+                );
+                record.result = format!("rejected by preflight: {}", reason);
+                audit::record(record).await;
+                let status = Status::new(
+                    Code::FailedPrecondition,
+                    format!("cork would revert: {}", reason),
+                );
+                return (Err(status), false);
+            }
+            Err(err) => {
+                error!("preflight call failed: {}", err);
+                let status = Status::new(
+                    Code::Unavailable,
+                    format!("failed to perform preflight call: {}", err),
+                );
+                return (Err(status), false);
+            }
+        }
+    }
+    let timer = metrics::SEND_CORK_DURATION.start_timer();
+    let result = send_cork(cork).await;
+    timer.observe_duration();
+    let response = match result {
+        Ok(r) => r,
+        Err(err) if *err.kind() == ErrorKind::InsufficientBalance => {
+            error!("refusing to send cork {}: {}", cork_id, err);
+            record.result = format!("not sent: {}", err);
+            audit::record(record).await;
+            let status = Status::new(
+                Code::FailedPrecondition,
+                format!("delegate account cannot pay fees: {}", err),
+            );
+            return (Err(status), false);
+        }
+        Err(err) => {
             error!("failed to submit cork: {}", err);
+            record.result = format!("send failed: {}", err);
+            audit::record(record).await;
+            let status = Status::new(
+                Code::Internal,
+                format!("failed to send cork to sommelier: {}", err),
+            );
+            return (Err(status), *err.kind() == ErrorKind::Unconfirmed);
+        }
+    };
+    if response.code != 0 {
+        warn!(
+            "cork {} for {} failed in tx {} with code {}: {}",
+            cork_id, cellar_id, response.txhash, response.code, response.raw_log
+        );
+        metrics::COSMOS_TX_FAILURES
+            .with_label_values(&[&response.code.to_string()])
+            .inc();
+        record.result = response.raw_log.clone();
+    } else {
+        info!(
+            "submitted cork {} for {} in tx {}",
+            cork_id, cellar_id, response.txhash
+        );
+        record.result = "ok".to_string();
+    }
+    record.tx_hash = response.txhash.clone();
+    record.height = response.height;
+    record.code = response.code;
+    audit::record(record).await;
+
+    let response = Response::new(SubmitResponse {
+        cork_id,
+        tx_hash: response.txhash,
+        height: response.height,
+        code: response.code,
+    });
+
+    (Ok(response), true)
+}
+
+/// Validates, builds and schedules the cork for a `Schedule` request
+async fn schedule_request(
+    request: Request<ScheduleRequest>,
+) -> Result<Response<ScheduleResponse>, Status> {
+    let identity = server::get_client_identity(&request);
+    let block_height = request.get_ref().block_height;
+    let request = match request.into_inner().request {
+        Some(r) => r,
+        None => return Err(Status::new(Code::InvalidArgument, "missing request")),
+    };
+    let adapter = check_request(CorkSource::Schedule, identity.as_ref(), &request).await?;
+
+    let current_height = match get_block_height().await {
+        Ok(h) => h,
+        Err(err) => {
+            error!("failed to query current block height: {}", err);
+            return Err(Status::new(
+                Code::Unavailable,
+                format!("failed to query current block height: {}", err),
+            ));
+        }
+    };
+    let horizon = APP.config().cork.schedule_horizon;
+    if block_height <= current_height {
+        return Err(Status::new(
+            Code::InvalidArgument,
+            format!(
+                "block height {} is not after the current height {}",
+                block_height, current_height
+            ),
+        ));
+    }
+    if block_height - current_height > horizon {
+        return Err(Status::new(
+            Code::InvalidArgument,
+            format!(
+                "block height {} is more than {} blocks after the current height {}",
+                block_height, horizon, current_height
+            ),
+        ));
+    }
+
+    let cellar_id = request.cellar_id.clone();
+    let cork = match build_cork(request.clone()).await {
+        Ok(c) => c,
+        Err(err) => {
+            warn!("failed to build cork for cellar {}: {}", cellar_id, err);
+            metrics::ENCODING_FAILURES
+                .with_label_values(&[metrics::cellar_label(&cellar_id)])
+                .inc();
+            let mut record = rejection_record(CorkSource::Schedule, identity.as_ref(), &request);
+            record.target_height = Some(block_height);
+            record.result = format!("rejected: {}", err);
+            audit::record(record).await;
+            return Err(Status::new(Code::InvalidArgument, err.to_string()));
+        }
+    };
+    let identity = identity.unwrap_or_default();
+    let cork_id = match get_cork_id(&cork) {
+        Ok(id) => hex::encode(id),
+        Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+    };
+    debug!("scheduling cork {} for height {}", cork_id, block_height);
+
+    let response = match send_scheduled_cork(cork, adapter.name(), block_height, identity).await {
+        Ok(r) => r,
+        Err(err) if *err.kind() == ErrorKind::InsufficientBalance => {
+            error!("refusing to schedule cork {}: {}", cork_id, err);
+            return Err(Status::new(
+                Code::FailedPrecondition,
+                format!("delegate account cannot pay fees: {}", err),
+            ));
+        }
+        Err(err) => {
+            error!("failed to schedule cork: {}", err);
             return Err(Status::new(
                 Code::Internal,
                 format!("failed to send cork to sommelier: {}", err),
             ));
         }
-        info!("submitted cork for {}", cellar_id);
+    };
+    if response.code != 0 {
+        warn!(
+            "scheduling cork {} for {} failed in tx {} with code {}: {}",
+            cork_id, cellar_id, response.txhash, response.code, response.raw_log
+        );
+        metrics::COSMOS_TX_FAILURES
+            .with_label_values(&[&response.code.to_string()])
+            .inc();
+    } else {
+        info!(
+            "scheduled cork {} for {} at height {} in tx {}",
+            cork_id, cellar_id, block_height, response.txhash
+        );
+    }
+
+    Ok(Response::new(ScheduleResponse {
+        cork_id,
+        tx_hash: response.txhash,
+        height: response.height,
+        code: response.code,
+        block_height,
+    }))
+}
 
-        Ok(Response::new(SubmitResponse {}))
+/// Applies the authorization policies, governance approval check and cellar rate limit shared by
+/// `Submit` and `Schedule`, returning the adapter for the requested cellar
+async fn check_request(
+    source: CorkSource,
+    identity: Option<&ClientIdentity>,
+    request: &SubmitRequest,
+) -> Result<&'static dyn CellarAdapter, Status> {
+    let config = APP.config();
+    let adapter = match cellars::get_adapter(request) {
+        Ok(a) => a,
+        Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+    };
+
+    // Check that the client may call these functions on this cellar
+    let call_data = request.call_data.as_ref();
+    let functions = match call_data.map(|c| adapter.invoked_functions(c)) {
+        Some(Ok(f)) => f,
+        Some(Err(err)) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+        None => Vec::new(),
+    };
+    if let Err(reason) = authorization::authorize(
+        &config.server.authorization.policies,
+        identity,
+        &request.cellar_id,
+        &functions,
+    ) {
+        info!("rejecting unauthorized request: {}", reason);
+        return Err(Status::new(Code::PermissionDenied, reason));
+    }
+
+    // Check if cellar is governance approved before building cork
+    debug!("checking if cellar ID is approved");
+    let approved = match cellar_ids::is_approved(&request.cellar_id).await {
+        Ok(a) => a,
+        Err(err) => {
+            error!("failed to query approved cellar IDs: {}", err);
+            return Err(Status::new(
+                Code::Internal,
+                format!("failed to query chain to validate cellar id: {}", err),
+            ));
+        }
+    };
+    if !approved {
+        info!(
+            "rejecting request for unapproved cellar {}",
+            request.cellar_id
+        );
+        metrics::GOVERNANCE_REJECTIONS.inc();
+        let mut record = rejection_record(source, identity, request);
+        record.result = "rejected: cellar not approved by governance".to_string();
+        audit::record(record).await;
+        return Err(Status::new(
+            Code::PermissionDenied,
+            format!(
+                "cellar ID {} not approved by governance",
+                &request.cellar_id
+            ),
+        ));
     }
+
+    // Checked after governance approval so that only approved cellars are tracked
+    if !CELLAR_LIMITER.check(&request.cellar_id.to_lowercase()) {
+        info!("rate limiting cellar {}", request.cellar_id);
+        metrics::RATE_LIMITED.with_label_values(&["cellar"]).inc();
+        return Err(Status::new(
+            Code::ResourceExhausted,
+            format!(
+                "request rate limit exceeded for cellar {}",
+                request.cellar_id
+            ),
+        ));
+    }
+
+    Ok(adapter)
 }
-// Because of Rusts handling of enums, we have no easy way to log what cellar type and function are
-// being requested before we get to the encoding step, so we pass the whole request into this method
-// and the get_encoded_call() methods so logging can happen there.
-async fn build_cork(request: SubmitRequest) -> Result<Cork, Error> {
-    cellars::validate_cellar_id(request.cellar_id.as_str())?;
 
-    let address = request.cellar_id.clone();
-    let encoded_call = get_encoded_call(request)?;
+/// Audit record for a request rejected before its cork was built
+fn rejection_record(
+    source: CorkSource,
+    identity: Option<&ClientIdentity>,
+    request: &SubmitRequest,
+) -> CorkRecord {
+    let identity = identity.cloned().unwrap_or_default();
+    let cellar_type = cellars::get_adapter(request)
+        .map(|a| a.name())
+        .unwrap_or_default();
+    let mut record = CorkRecord::new(
+        source,
+        identity.subject,
+        identity.fingerprint,
+        request.cellar_id.clone(),
+        cellar_type,
+        &[],
+    );
+    record.function = get_function_label(request);
 
-    Ok(Cork {
-        encoded_contract_call: encoded_call,
-        target_contract_address: address,
-    })
+    record
 }
 
-fn get_encoded_call(request: SubmitRequest) -> Result<Vec<u8>, Error> {
-    if request.call_data.is_none() {
-        return Err(ErrorKind::Http.context("empty contract call data").into());
+/// Name of the requested contract function for metric labels
+fn get_function_label(request: &SubmitRequest) -> String {
+    let adapter = match cellars::get_adapter(request) {
+        Ok(a) => a,
+        Err(_) => return "unknown".to_string(),
+    };
+
+    request
+        .call_data
+        .as_ref()
+        .and_then(|c| adapter.function_name(c).ok())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Computes the ID of a cork, the keccak256 hash of the target contract address concatenated with
+/// the encoded contract call. The Gravity bridge uses the same value as the invalidation scope of
+/// the contract call that executes the cork on Ethereum.
+pub fn get_cork_id(cork: &Cork) -> Result<[u8; 32], Error> {
+    let address = match cork.target_contract_address.parse::<H160>() {
+        Ok(a) => a,
+        Err(err) => {
+            return Err(ErrorKind::SPCallError
+                .context(format!("invalid ethereum address: {}", err))
+                .into())
+        }
+    };
+    let mut bytes = address.as_bytes().to_vec();
+    bytes.extend_from_slice(&cork.encoded_contract_call);
+
+    Ok(keccak256(bytes))
+}
+
+// Cellar functions called through corks require the Gravity contract to be the msg.sender, so we
+// perform the call from its address. Returns the decoded revert reason if the call would fail.
+async fn preflight_cork(cork: &Cork, abi_json: &str) -> Result<Option<String>, Error> {
+    let config = APP.config();
+    let gravity = match config.gravity.contract.parse::<H160>() {
+        Ok(a) => a,
+        Err(err) => {
+            return Err(ErrorKind::Config
+                .context(format!("invalid gravity contract address: {}", err))
+                .into())
+        }
+    };
+    let cellar = match cork.target_contract_address.parse::<H160>() {
+        Ok(a) => a,
+        Err(err) => {
+            return Err(ErrorKind::SPCallError
+                .context(format!("invalid ethereum address: {}", err))
+                .into())
+        }
+    };
+
+    let provider = utils::get_eth_provider().await?;
+    let tx = TransactionRequest::new()
+        .from(gravity)
+        .to(cellar)
+        .data(cork.encoded_contract_call.clone());
+    let err = match provider.call(&tx.into(), None).await {
+        Ok(_) => return Ok(None),
+        Err(err) => err,
+    };
+
+    if let ProviderError::JsonRpcClientError(inner) = &err {
+        if let Some(HttpClientError::JsonRpcError(rpc_err)) =
+            inner.downcast_ref::<HttpClientError>()
+        {
+            if let Some(reason) = get_revert_reason(rpc_err, abi_json) {
+                return Ok(Some(reason));
+            }
+        }
+    }
+
+    Err(err.into())
+}
+
+// Nodes report a reverted call with error code 3 and the revert data. Any other JSON-RPC error,
+// such as a rate limit or an unsupported method, is a problem with the node rather than the cork.
+fn get_revert_reason(rpc_err: &JsonRpcError, abi_json: &str) -> Option<String> {
+    let data = rpc_err
+        .data
+        .as_ref()
+        .and_then(|d| d.as_str())
+        .and_then(|d| hex::decode(d.trim_start_matches("0x")).ok())
+        .filter(|d| !d.is_empty());
+
+    match data {
+        Some(d) => Some(cellars::decode_revert(abi_json, &d)),
+        None if rpc_err.code == 3 => Some(rpc_err.message.clone()),
+        None => None,
     }
+}
 
-    match request.call_data.unwrap() {
-        AaveV2Stablecoin(call) => {
-            if call.function.is_none() {
-                return Err(ErrorKind::Http.context("empty function data").into());
+// A cork moves from an included transaction, to a pending vote in the cork module, to an outgoing
+// contract call in the Gravity module once approved. Once the contract call has been relayed to
+// Ethereum it is pruned from the Gravity module and we can only report the inclusion of the
+// original transaction.
+async fn get_cork_status(
+    cork_id: Vec<u8>,
+    tx_hash: String,
+) -> Result<GetCorkStatusResponse, Error> {
+    let mut response = GetCorkStatusResponse::default();
+    response.set_status(CorkStatus::NotFound);
+
+    if !tx_hash.is_empty() {
+        debug!("querying submission tx {}", tx_hash);
+        let mut client = TxServiceClient::connect(cosmos_endpoints::current()).await?;
+        match client.get_tx(GetTxRequest { hash: tx_hash }).await {
+            Ok(res) => {
+                if let Some(tx) = res.into_inner().tx_response {
+                    response.height = tx.height;
+                    response.code = tx.code;
+                    if tx.code != 0 {
+                        response.set_status(CorkStatus::Failed);
+                        return Ok(response);
+                    }
+                    response.set_status(CorkStatus::Included);
+                }
+            }
+            Err(status) if status.code() == Code::NotFound => {
+                debug!("submission tx not found");
             }
+            Err(status) => return Err(status.into()),
+        }
+    }
+
+    debug!("checking submitted corks");
+    let mut client = CorkQueryClient::new(cosmos_endpoints::get_channel()?);
+    let corks = client
+        .query_submitted_corks(QuerySubmittedCorksRequest {})
+        .await?
+        .into_inner()
+        .corks;
+    if corks
+        .iter()
+        .any(|c| matches!(get_cork_id(c), Ok(id) if id[..] == cork_id[..]))
+    {
+        response.set_status(CorkStatus::Pending);
+        return Ok(response);
+    }
 
-            aave_v2_stablecoin::get_encoded_call(call.function.unwrap(), request.cellar_id)
+    debug!("checking outgoing gravity contract calls");
+    let mut client = GravityQueryClient::connect(cosmos_endpoints::current()).await?;
+    let mut next_key = Vec::new();
+    loop {
+        let page = client
+            .contract_call_txs(ContractCallTxsRequest {
+                pagination: Some(PageRequest {
+                    key: next_key,
+                    ..Default::default()
+                }),
+            })
+            .await?
+            .into_inner();
+        if page.calls.iter().any(|c| c.invalidation_scope == cork_id) {
+            response.set_status(CorkStatus::Bridged);
+            break;
         }
+
+        next_key = match page.pagination {
+            Some(p) if !p.next_key.is_empty() => p.next_key,
+            _ => break,
+        };
     }
+
+    Ok(response)
 }
 
+async fn build_cork(request: SubmitRequest) -> Result<Cork, Error> {
+    let address = request.cellar_id.clone();
+    let encoded_call = cellars::get_encoded_call(request)?;
+
+    Ok(Cork {
+        encoded_contract_call: encoded_call,
+        target_contract_address: address,
+    })
+}
+
+/// Sends `cork` to Sommelier, batched with other corks if `cork.batch_window_ms` is set
 async fn send_cork(cork: Cork) -> Result<TxResponse, Error> {
+    if APP.config().cork.batch_window_ms > 0 {
+        return batch::send(cork).await;
+    }
+
+    send_corks(vec![cork]).await
+}
+
+/// Sends `corks` to Sommelier in a single transaction
+async fn send_corks(corks: Vec<Cork>) -> Result<TxResponse, Error> {
     let config = APP.config();
     debug!("establishing grpc connection");
-    let contact = Contact::new(&config.cosmos.grpc, MESSAGE_TIMEOUT, CHAIN_PREFIX)?;
+    let contacts = get_contacts()?;
 
-    debug!("getting cosmos fee");
-    let cosmos_gas_price = config.cosmos.gas_price.as_tuple();
-    let fee = Coin {
-        amount: (cosmos_gas_price.0 as u64).into(),
-        denom: cosmos_gas_price.1,
-    };
-    somm_send::send_cork(
-        &contact,
-        cork,
+    somm_send::send_corks(
+        &contacts,
+        corks,
         config::DELEGATE_ADDRESS.to_string(),
         &config::DELEGATE_KEY,
-        fee,
+        config.cosmos.gas_price.as_tuple(),
+        config.cosmos.gas_adjustment,
     )
     .await
-    .map_err(|e| e.into())
+}
+
+/// Returns a contact for each configured endpoint, healthy ones first
+fn get_contacts() -> Result<Vec<Contact>, Error> {
+    cosmos_endpoints::ordered()
+        .iter()
+        .map(|url| Contact::new(url, MESSAGE_TIMEOUT, CHAIN_PREFIX).map_err(|e| e.into()))
+        .collect()
+}
+
+/// Returns the latest block height of the Sommelier chain
+async fn get_block_height() -> Result<u64, Error> {
+    let contact = Contact::new(&cosmos_endpoints::current(), MESSAGE_TIMEOUT, CHAIN_PREFIX)?;
+    match contact.get_chain_status().await? {
+        ChainStatus::Moving { block_height } => Ok(block_height),
+        ChainStatus::Syncing => Err(ErrorKind::GrpcError
+            .context("sommelier node is syncing")
+            .into()),
+        ChainStatus::WaitingToStart => Err(ErrorKind::GrpcError
+            .context("sommelier chain has not started")
+            .into()),
+    }
 }
 
 pub async fn schedule_cork(
     contract: String,
+    cellar_type: &str,
     encoded_call: Vec<u8>,
     height: u64,
 ) -> Result<TxResponse, Error> {
-    let config = APP.config();
-    debug!("establishing grpc connection");
-    let contact = Contact::new(&config.cosmos.grpc, MESSAGE_TIMEOUT, CHAIN_PREFIX).unwrap();
-
-    debug!("getting cosmos fee");
-    let cosmos_gas_price = config.cosmos.gas_price.as_tuple();
-    let fee = Coin {
-        amount: (cosmos_gas_price.0 as u64).into(),
-        denom: cosmos_gas_price.1,
-    };
     let cork = Cork {
         encoded_contract_call: encoded_call,
-        target_contract_address: contract.clone(),
+        target_contract_address: contract,
+    };
+    let requester = ClientIdentity {
+        subject: "cli".to_string(),
+        fingerprint: String::new(),
     };
-    somm_send::schedule_cork(
-        &contact,
+
+    send_scheduled_cork(cork, cellar_type, height, requester).await
+}
+
+/// Schedules `cork` for `height` and records it in the audit log under `requester`, decoding its
+/// call with the ABI of `cellar_type`
+async fn send_scheduled_cork(
+    cork: Cork,
+    cellar_type: &str,
+    height: u64,
+    requester: ClientIdentity,
+) -> Result<TxResponse, Error> {
+    let config = APP.config();
+    debug!("establishing grpc connection");
+    let contacts = get_contacts()?;
+
+    let mut record = CorkRecord::new(
+        CorkSource::Schedule,
+        requester.subject,
+        requester.fingerprint,
+        cork.target_contract_address.clone(),
+        cellar_type,
+        &cork.encoded_contract_call,
+    );
+    record.cork_id = get_cork_id(&cork).map(hex::encode).unwrap_or_default();
+    record.target_height = Some(height);
+    let result = somm_send::schedule_cork(
+        &contacts,
         cork,
         config::DELEGATE_ADDRESS.to_string(),
         &config::DELEGATE_KEY,
-        fee,
+        config.cosmos.gas_price.as_tuple(),
+        config.cosmos.gas_adjustment,
         height,
     )
-    .await
-    .map_err(|e| e.into())
+    .await;
+    match &result {
+        Ok(response) => {
+            record.tx_hash = response.txhash.clone();
+            record.height = response.height;
+            record.code = response.code;
+            record.result = if response.code == 0 {
+                "ok".to_string()
+            } else {
+                response.raw_log.clone()
+            };
+        }
+        Err(err) => record.result = format!("send failed: {}", err),
+    }
+    audit::record(record).await;
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cork_id_hashes_address_and_call() {
+        let cork = Cork {
+            encoded_contract_call: vec![0x0b, 0xad, 0xc0, 0xde],
+            target_contract_address: "0x0000000000000000000000000000000000000000".to_string(),
+        };
+        let result = get_cork_id(&cork).unwrap();
+
+        assert_eq!(
+            hex::encode(result),
+            "cca20dbce7b8646680bb263fa83cb1291fe24c3b7bbe4625de9d428f927354ec"
+        );
+    }
+
+    #[test]
+    fn cork_id_invalid_address_errors() {
+        let cork = Cork {
+            encoded_contract_call: vec![],
+            target_contract_address: "thisaintright".to_string(),
+        };
+        let result = get_cork_id(&cork);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn revert_reason_requires_revert_code_or_data() {
+        let abi_json = cellars::aave_v2_stablecoin::ABI_JSON;
+        let rpc_err = |code, data: Option<&str>| JsonRpcError {
+            code,
+            message: "error".to_string(),
+            data: data.map(|d| serde_json::Value::String(d.to_string())),
+        };
+
+        assert_eq!(
+            get_revert_reason(&rpc_err(3, None), abi_json),
+            Some("error".to_string())
+        );
+        assert!(get_revert_reason(&rpc_err(-32000, Some("0x08c379a0")), abi_json).is_some());
+        assert_eq!(get_revert_reason(&rpc_err(-32005, None), abi_json), None);
+        assert_eq!(
+            get_revert_reason(&rpc_err(-32601, Some("0x")), abi_json),
+            None
+        );
+    }
 }
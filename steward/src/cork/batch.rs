@@ -0,0 +1,149 @@
+//! Batching of corks submitted close together into a single Cosmos transaction
+//!
+//! When `cork.batch_window_ms` is nonzero, a cork sent while no batch is open starts one. Corks
+//! sent within the window join it, up to `cosmos.msg_batch_size` corks, and the batch is then
+//! broadcast as one transaction whose result is returned to every sender. If the transaction
+//! failed because of one of its corks, that cork's sender gets the failure and the rest of the
+//! batch is sent again without it.
+
+use super::send_corks;
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{debug, error},
+    Application,
+};
+use gravity_bridge::gravity_proto::cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use lazy_static::lazy_static;
+use regex::Regex;
+use somm_proto::cork::Cork;
+use std::{sync::Mutex, time::Duration};
+use tokio::sync::{mpsc, oneshot};
+
+/// The result of a batch, shared by each of its senders. Errors are reduced to their kind and
+/// message so that they can be cloned.
+type BatchResult = Result<TxResponse, (ErrorKind, String)>;
+
+struct PendingCork {
+    cork: Cork,
+    reply: oneshot::Sender<BatchResult>,
+}
+
+lazy_static! {
+    static ref QUEUE: Mutex<Option<mpsc::UnboundedSender<PendingCork>>> = Mutex::new(None);
+    /// Index of the message that failed a transaction, as reported in its log
+    static ref FAILED_MESSAGE_INDEX: Regex = Regex::new(r"message index: (\d+)").unwrap();
+}
+
+/// Adds `cork` to the open batch, or opens one, and returns the result of the batch's transaction
+pub async fn send(cork: Cork) -> Result<TxResponse, Error> {
+    let (reply, response) = oneshot::channel();
+    if get_queue().send(PendingCork { cork, reply }).is_err() {
+        return Err(ErrorKind::GrpcError
+            .context("cork batcher has stopped")
+            .into());
+    }
+
+    match response.await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err((kind, message))) => Err(kind.context(message).into()),
+        Err(_) => Err(ErrorKind::GrpcError
+            .context("cork batch was dropped before it was sent")
+            .into()),
+    }
+}
+
+/// Returns the sender for the batcher task, starting the task on first use
+fn get_queue() -> mpsc::UnboundedSender<PendingCork> {
+    let mut queue = QUEUE.lock().unwrap();
+    if let Some(q) = queue.as_ref() {
+        return q.clone();
+    }
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(collect_batches(rx));
+    *queue = Some(tx.clone());
+
+    tx
+}
+
+async fn collect_batches(mut rx: mpsc::UnboundedReceiver<PendingCork>) {
+    let config = APP.config();
+    let window = Duration::from_millis(config.cork.batch_window_ms);
+    let max_size = config.cosmos.msg_batch_size.max(1) as usize;
+    while let Some(first) = rx.recv().await {
+        let deadline = tokio::time::Instant::now() + window;
+        let mut batch = vec![first];
+        while batch.len() < max_size {
+            match tokio::time::timeout_at(deadline, rx.recv()).await {
+                Ok(Some(pending)) => batch.push(pending),
+                _ => break,
+            }
+        }
+
+        // Sent in its own task so the next batch can be collected while this one is included
+        tokio::spawn(send_batch(batch));
+    }
+}
+
+async fn send_batch(mut batch: Vec<PendingCork>) {
+    while !batch.is_empty() {
+        let corks: Vec<Cork> = batch.iter().map(|p| p.cork.clone()).collect();
+        debug!("sending batch of {} corks", corks.len());
+
+        let result = send_corks(corks).await.map_err(|err| {
+            error!("failed to send cork batch: {}", err);
+            let message = match std::error::Error::source(&err) {
+                Some(source) => source.to_string(),
+                None => err.to_string(),
+            };
+            (*err.kind(), message)
+        });
+        let log = match &result {
+            Ok(response) if response.code != 0 => Some(response.raw_log.as_str()),
+            Ok(_) => None,
+            Err((_, message)) => Some(message.as_str()),
+        };
+        let failed = log
+            .and_then(get_failed_message_index)
+            .filter(|&i| batch.len() > 1 && i < batch.len());
+        match failed {
+            Some(index) => {
+                debug!(
+                    "cork {} failed the batch, sending the other {} again",
+                    index,
+                    batch.len() - 1
+                );
+                // The sender may have gone away if its request was cancelled
+                let _ = batch.remove(index).reply.send(result);
+            }
+            None => {
+                for pending in batch.drain(..) {
+                    let _ = pending.reply.send(result.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Returns the index of the message that failed a transaction from its log, if it names one
+fn get_failed_message_index(log: &str) -> Option<usize> {
+    FAILED_MESSAGE_INDEX
+        .captures(log)
+        .and_then(|c| c[1].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_message_index_is_parsed_from_log() {
+        let log = "failed to execute message; message index: 2: cellar not found: unauthorized";
+
+        assert_eq!(get_failed_message_index(log), Some(2));
+        assert_eq!(get_failed_message_index("out of gas"), None);
+    }
+}
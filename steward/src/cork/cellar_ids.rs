@@ -0,0 +1,107 @@
+//! In-memory set of the cellar IDs approved by governance
+//!
+//! The set is kept fresh by [`refresh_loop`] so that `Submit` requests don't each query Sommelier.
+//! A lookup refreshes the set first if it has grown older than `cork.cellar_ids_max_staleness`,
+//! and a miss triggers an immediate refresh so newly approved cellars are accepted right away.
+
+use crate::{cosmos_endpoints, error::Error, prelude::APP};
+use abscissa_core::{
+    tracing::log::{debug, error},
+    Application,
+};
+use lazy_static::lazy_static;
+use somm_proto::cork::{query_client::QueryClient as CorkQueryClient, QueryCellarIDsRequest};
+use std::{
+    collections::HashSet,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+/// Minimum time between refreshes triggered by unknown cellar IDs, so a burst of requests for an
+/// unapproved cellar results in at most one query per interval
+const MISS_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+struct ApprovedCellars {
+    ids: HashSet<String>,
+    refreshed_at: Option<Instant>,
+}
+
+lazy_static! {
+    static ref APPROVED_CELLARS: RwLock<ApprovedCellars> = RwLock::new(ApprovedCellars::default());
+    static ref REFRESH_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+}
+
+/// Whether governance has approved `cellar_id`
+pub async fn is_approved(cellar_id: &str) -> Result<bool, Error> {
+    let config = APP.config();
+    let max_staleness = Duration::from_secs(config.cork.cellar_ids_max_staleness);
+    let (age, found) = {
+        let cache = APPROVED_CELLARS.read().unwrap();
+        (
+            cache.refreshed_at.map(|t| t.elapsed()),
+            cache.ids.contains(cellar_id),
+        )
+    };
+    let fresh = matches!(age, Some(age) if age <= max_staleness);
+    if fresh && (found || matches!(age, Some(age) if age < MISS_REFRESH_INTERVAL)) {
+        return Ok(found);
+    }
+
+    debug!(
+        "refreshing approved cellar IDs while checking {}",
+        cellar_id
+    );
+    if let Err(err) = refresh().await {
+        // A miss against a set that is still within its staleness bound can be answered from
+        // the cache
+        if !fresh {
+            return Err(err);
+        }
+        error!("failed to refresh approved cellar IDs: {}", err);
+    }
+
+    Ok(APPROVED_CELLARS.read().unwrap().ids.contains(cellar_id))
+}
+
+/// Whether `cellar_id` is in the cached set of approved cellar IDs, without refreshing it
+pub fn is_cached(cellar_id: &str) -> bool {
+    APPROVED_CELLARS.read().unwrap().ids.contains(cellar_id)
+}
+
+/// Queries the approved cellar IDs from Sommelier and replaces the cached set. Concurrent callers
+/// share a single query.
+pub async fn refresh() -> Result<(), Error> {
+    let requested_at = Instant::now();
+    let _guard = REFRESH_LOCK.lock().await;
+    if matches!(APPROVED_CELLARS.read().unwrap().refreshed_at, Some(t) if t >= requested_at) {
+        return Ok(());
+    }
+
+    let mut client = CorkQueryClient::new(cosmos_endpoints::get_channel()?);
+    let ids = client
+        .query_cellar_i_ds(QueryCellarIDsRequest {})
+        .await?
+        .into_inner()
+        .cellar_ids;
+    debug!("refreshed {} approved cellar IDs", ids.len());
+
+    let mut cache = APPROVED_CELLARS.write().unwrap();
+    cache.ids = ids.into_iter().collect();
+    cache.refreshed_at = Some(Instant::now());
+
+    Ok(())
+}
+
+/// Refreshes the approved cellar IDs every `cork.cellar_ids_refresh_interval` seconds. Runs until
+/// the process exits.
+pub async fn refresh_loop() {
+    let period = Duration::from_secs(APP.config().cork.cellar_ids_refresh_interval.max(1));
+    loop {
+        if let Err(err) = refresh().await {
+            error!("failed to refresh approved cellar IDs: {}", err);
+        }
+
+        tokio::time::sleep(period).await;
+    }
+}
@@ -0,0 +1,99 @@
+//! Deduplication of retried `Submit` requests
+//!
+//! A client that retries a Submit after a network timeout would otherwise broadcast the same cork
+//! twice. Requests are identified by the client's certificate fingerprint together with either the
+//! request's `idempotency_key`, remembered for `cork.idempotency_key_ttl` seconds, or the cork ID,
+//! remembered for `cork.dedup_window` seconds. Keys are derived once a request has passed the
+//! authorization and governance checks and its cork has been built. A duplicate of a request whose
+//! cork may have been broadcast gets the original response or error, even if the transaction
+//! wasn't confirmed; a duplicate of a request still in flight waits for it. Requests that failed
+//! before a transaction was broadcast are not remembered, so they can be retried.
+
+use crate::{metrics, prelude::APP};
+use abscissa_core::{tracing::log::info, Application};
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use steward_proto::steward::SubmitResponse;
+use tonic::{Response, Status};
+
+/// The result of the first request with a key, once its cork may have been broadcast
+type Slot = Arc<tokio::sync::Mutex<Option<(Instant, Result<SubmitResponse, Status>)>>>;
+
+lazy_static! {
+    static ref SLOTS: Mutex<HashMap<String, (Duration, Slot)>> = Mutex::new(HashMap::new());
+}
+
+/// Returns the deduplication key of a request from the client with certificate fingerprint
+/// `client` and how long it is remembered, or `None` if the request shouldn't be deduplicated
+pub fn get_key(client: &str, idempotency_key: &str, cork_id: &str) -> Option<(String, Duration)> {
+    let config = APP.config();
+    if !idempotency_key.is_empty() {
+        let ttl = Duration::from_secs(config.cork.idempotency_key_ttl);
+        return Some((format!("{}:key:{}", client, idempotency_key), ttl));
+    }
+
+    if config.cork.dedup_window == 0 {
+        return None;
+    }
+    let window = Duration::from_secs(config.cork.dedup_window);
+
+    Some((format!("{}:cork:{}", client, cork_id), window))
+}
+
+/// Runs `submit` unless a request with `key` was already broadcast within `ttl`, in which case the
+/// original result is returned. `submit` resolves to its result and whether a transaction may
+/// have been broadcast for it.
+pub async fn run<F>(
+    key: String,
+    ttl: Duration,
+    submit: F,
+) -> Result<Response<SubmitResponse>, Status>
+where
+    F: Future<Output = (Result<Response<SubmitResponse>, Status>, bool)>,
+{
+    let slot = get_slot(&key, ttl);
+    let mut sent = slot.lock().await;
+    if let Some((sent_at, result)) = sent.as_ref() {
+        if sent_at.elapsed() <= ttl {
+            info!("returning original result to duplicate request {}", key);
+            metrics::DUPLICATE_SUBMITS.inc();
+            return result.clone().map(Response::new);
+        }
+    }
+
+    let (result, broadcast) = submit.await;
+    if broadcast {
+        let original = match &result {
+            Ok(response) => Ok(response.get_ref().clone()),
+            Err(status) => Err(status.clone()),
+        };
+        *sent = Some((Instant::now(), original));
+    }
+
+    result
+}
+
+fn get_slot(key: &str, ttl: Duration) -> Slot {
+    let mut slots = SLOTS.lock().unwrap();
+    // Forget slots that no request is using and that don't hold an unexpired response
+    slots.retain(|_, (ttl, slot)| {
+        if Arc::strong_count(slot) > 1 {
+            return true;
+        }
+        match slot.try_lock() {
+            Ok(sent) => matches!(sent.as_ref(), Some((sent_at, _)) if sent_at.elapsed() <= *ttl),
+            Err(_) => true,
+        }
+    });
+
+    slots
+        .entry(key.to_string())
+        .or_insert_with(|| (ttl, Slot::default()))
+        .1
+        .clone()
+}
@@ -0,0 +1,82 @@
+//! Guard against sending corks from a delegate account that can't pay for them
+//!
+//! A delegate account that runs out of the fee denom silently stops submitting corks, so its
+//! balance is checked before every broadcast. Sends are refused below `cosmos.fee_balance_floor`,
+//! and a warning is logged when the balance falls below `cosmos.fee_balance_warning_threshold`.
+
+use crate::{
+    config,
+    error::{Error, ErrorKind},
+    metrics,
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{error, info, warn},
+    Application,
+};
+use deep_space::{Coin, Contact};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the balance was below the warning threshold when last observed
+static BELOW_WARNING_THRESHOLD: AtomicBool = AtomicBool::new(false);
+
+/// Queries the delegate account's fee balance and returns an `InsufficientBalance` error if it is
+/// below the configured floor
+pub async fn check(contact: &Contact) -> Result<(), Error> {
+    let balances = contact.get_balances(*config::DELEGATE_ADDRESS).await?;
+    let balance = observe(&balances);
+    let config = APP.config();
+    let floor = u128::from(config.cosmos.fee_balance_floor);
+    if balance < floor {
+        return Err(ErrorKind::InsufficientBalance
+            .context(format!(
+                "delegate balance of {}{} is below the floor of {}{}",
+                balance, config.cosmos.gas_price.denom, floor, config.cosmos.gas_price.denom
+            ))
+            .into());
+    }
+
+    Ok(())
+}
+
+/// Records the fee denom balance in `balances` and warns if it has crossed below the warning
+/// threshold. Returns the balance, which is zero if it cannot be parsed so that sends are refused
+/// rather than allowed on a balance that is unknown.
+pub fn observe(balances: &[Coin]) -> u128 {
+    let config = APP.config();
+    let denom = &config.cosmos.gas_price.denom;
+    let balance = balances
+        .iter()
+        .find(|c| &c.denom == denom)
+        .map(|c| {
+            c.amount.to_string().parse::<u128>().unwrap_or_else(|err| {
+                error!(
+                    "treating unparseable delegate balance {}{} as zero: {}",
+                    c.amount, denom, err
+                );
+                0
+            })
+        })
+        .unwrap_or_default();
+    metrics::DELEGATE_BALANCE
+        .with_label_values(&[denom.as_str()])
+        .set(balance as f64);
+
+    let threshold = u128::from(config.cosmos.fee_balance_warning_threshold);
+    let low = balance < threshold;
+    metrics::DELEGATE_BALANCE_LOW.set(if low { 1.0 } else { 0.0 });
+    let was_low = BELOW_WARNING_THRESHOLD.swap(low, Ordering::Relaxed);
+    if low && !was_low {
+        warn!(
+            "delegate balance of {}{} is below the warning threshold of {}{}, top up {} to keep submitting corks",
+            balance, denom, threshold, denom, *config::DELEGATE_ADDRESS
+        );
+    } else if !low && was_low {
+        info!(
+            "delegate balance of {}{} is back above the warning threshold",
+            balance, denom
+        );
+    }
+
+    balance
+}
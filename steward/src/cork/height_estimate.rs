@@ -0,0 +1,144 @@
+//! Estimation of the Sommelier block height that will be reached at a given time
+//!
+//! The average block time is measured over the last [`LONG_WINDOW`] blocks. The margin is the
+//! difference from the estimate using only the last [`SHORT_WINDOW`] blocks, which reflects how
+//! much block times have drifted recently.
+
+use crate::{
+    cosmos_endpoints,
+    error::{Error, ErrorKind},
+};
+use chrono::{DateTime, TimeZone, Utc};
+use deep_space::{client::LatestBlock, Contact};
+
+use super::{CHAIN_PREFIX, MESSAGE_TIMEOUT};
+
+const LONG_WINDOW: u64 = 1000;
+const SHORT_WINDOW: u64 = 100;
+
+/// An estimated block height and the measurements it was made from
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeightEstimate {
+    /// Estimated height of the first block at or after the target time
+    pub height: u64,
+    /// Number of blocks by which the estimate may be off
+    pub margin: u64,
+    /// Average block time in seconds over the last [`LONG_WINDOW`] blocks
+    pub block_time: f64,
+    pub latest_height: u64,
+    pub latest_time: DateTime<Utc>,
+}
+
+/// Estimates the height of the first block at or after `target` from recent block times
+pub async fn estimate(target: DateTime<Utc>) -> Result<HeightEstimate, Error> {
+    let contact = Contact::new(&cosmos_endpoints::current(), MESSAGE_TIMEOUT, CHAIN_PREFIX)?;
+    let block = match contact.get_latest_block().await? {
+        LatestBlock::Latest { block } => block,
+        LatestBlock::Syncing { .. } => {
+            return Err(ErrorKind::GrpcError
+                .context("sommelier node is syncing")
+                .into())
+        }
+        LatestBlock::WaitingToStart => {
+            return Err(ErrorKind::GrpcError
+                .context("sommelier chain has not started")
+                .into())
+        }
+    };
+    let header = block
+        .header
+        .ok_or_else(|| ErrorKind::GrpcError.context("latest block has no header"))?;
+    let time = header
+        .time
+        .ok_or_else(|| ErrorKind::GrpcError.context("latest block has no time"))?;
+    let latest_height = header.height as u64;
+    let latest_time = Utc.timestamp(time.seconds, time.nanos as u32);
+
+    let mut block_times = Vec::new();
+    for window in [LONG_WINDOW, SHORT_WINDOW] {
+        let window = window.min(latest_height.saturating_sub(1));
+        if window == 0 {
+            return Err(ErrorKind::GrpcError
+                .context("not enough blocks to measure block time")
+                .into());
+        }
+
+        let height = latest_height - window;
+        let time = contact
+            .get_block(height)
+            .await?
+            .and_then(|b| b.header)
+            .and_then(|h| h.time)
+            .ok_or_else(|| {
+                ErrorKind::GrpcError.context(format!(
+                    "block {} or its time is unavailable, it may have been pruned",
+                    height
+                ))
+            })?;
+        let elapsed = latest_time - Utc.timestamp(time.seconds, time.nanos as u32);
+        block_times.push(elapsed.num_milliseconds() as f64 / 1000.0 / window as f64);
+    }
+
+    from_block_times(
+        target,
+        latest_height,
+        latest_time,
+        block_times[0],
+        block_times[1],
+    )
+}
+
+/// Estimates the height at `target` from average block times over a long and a short window
+fn from_block_times(
+    target: DateTime<Utc>,
+    latest_height: u64,
+    latest_time: DateTime<Utc>,
+    long_block_time: f64,
+    short_block_time: f64,
+) -> Result<HeightEstimate, Error> {
+    if long_block_time <= 0.0 || short_block_time <= 0.0 {
+        return Err(ErrorKind::GrpcError
+            .context("recent block times are not increasing")
+            .into());
+    }
+
+    let seconds = (target - latest_time).num_milliseconds() as f64 / 1000.0;
+    if seconds <= 0.0 {
+        return Err(ErrorKind::Config
+            .context(format!(
+                "target time {} is not after the latest block at {}",
+                target.to_rfc3339(),
+                latest_time.to_rfc3339()
+            ))
+            .into());
+    }
+
+    let blocks = (seconds / long_block_time).ceil() as u64;
+    let short_blocks = (seconds / short_block_time).ceil() as u64;
+
+    Ok(HeightEstimate {
+        height: latest_height + blocks,
+        margin: (blocks as i64 - short_blocks as i64).unsigned_abs().max(1),
+        block_time: long_block_time,
+        latest_height,
+        latest_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_uses_long_window_and_short_window_margin() {
+        let latest_time = Utc.timestamp(1_650_000_000, 0);
+        let target = latest_time + chrono::Duration::seconds(600);
+        let result = from_block_times(target, 1000, latest_time, 6.0, 5.0).unwrap();
+
+        assert_eq!(result.height, 1100);
+        assert_eq!(result.margin, 20);
+
+        let past = latest_time - chrono::Duration::seconds(1);
+        assert!(from_block_times(past, 1000, latest_time, 6.0, 5.0).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! Corks scheduled by this Steward's validator that the cork module has yet to submit
+//!
+//! The cork module records scheduled corks against the validator rather than the delegate address
+//! that signed them, so the validator is looked up from the delegate address first.
+
+use crate::{
+    cellars::{self, DecodedCall},
+    config, cosmos_endpoints,
+    error::Error,
+    utils,
+};
+use abscissa_core::tracing::log::debug;
+use gravity_bridge::gravity_proto::gravity::query_client::QueryClient as GravityQueryClient;
+use somm_proto::cork::{query_client::QueryClient as CorkQueryClient, QueryScheduledCorksRequest};
+
+use super::get_cork_id;
+
+/// A pending scheduled cork and its decoded contract call
+#[derive(Debug)]
+pub struct ScheduledCork {
+    /// Hex-encoded cork ID
+    pub cork_id: String,
+    pub cellar_id: String,
+    pub block_height: u64,
+    pub encoded_call: Vec<u8>,
+    /// `None` if no registered cellar ABI could decode the call
+    pub call: Option<DecodedCall>,
+}
+
+/// Returns the corks scheduled by this Steward's validator, ordered by block height. If
+/// `cellar_id` is set only corks targeting that cellar are returned.
+pub async fn list(cellar_id: Option<&str>) -> Result<Vec<ScheduledCork>, Error> {
+    let channel = cosmos_endpoints::get_channel()?;
+    let mut gravity = GravityQueryClient::new(channel.clone());
+    let validator = utils::get_delegates_keys_by_orchestrator(
+        &mut gravity,
+        config::DELEGATE_ADDRESS.to_string(),
+    )
+    .await?
+    .validator_address;
+    debug!("listing corks scheduled by validator {}", validator);
+
+    let mut client = CorkQueryClient::new(channel);
+    let mut corks: Vec<ScheduledCork> = client
+        .scheduled_corks(QueryScheduledCorksRequest {})
+        .await?
+        .into_inner()
+        .corks
+        .into_iter()
+        .filter(|s| s.validator == validator)
+        .filter_map(|s| {
+            let cork = s.cork?;
+            Some(ScheduledCork {
+                cork_id: get_cork_id(&cork).map(hex::encode).unwrap_or_default(),
+                call: cellars::decode_call(&cork.encoded_contract_call).ok(),
+                cellar_id: cork.target_contract_address,
+                block_height: s.block_height,
+                encoded_call: cork.encoded_contract_call,
+            })
+        })
+        .filter(|s| match cellar_id {
+            Some(id) => s.cellar_id.eq_ignore_ascii_case(id),
+            None => true,
+        })
+        .collect();
+    corks.sort_by_key(|s| s.block_height);
+
+    Ok(corks)
+}
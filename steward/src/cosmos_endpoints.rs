@@ -0,0 +1,165 @@
+//! Health-checked failover between the configured Sommelier gRPC endpoints
+//!
+//! The endpoints are `cosmos.grpc` followed by `cosmos.grpc_endpoints`. While `steward start` is
+//! running, [`health_check_loop`] marks an endpoint unhealthy if it can't be reached, its node is
+//! syncing, or its latest block is older than `cosmos.max_block_age`. Queries and broadcasts use
+//! the first healthy endpoint in configured order, falling back to unhealthy ones only if none are
+//! healthy.
+
+use crate::{
+    error::{Error, ErrorKind},
+    metrics,
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{info, warn},
+    Application,
+};
+use gravity_bridge::gravity_proto::cosmos_sdk_proto::cosmos::base::tendermint::v1beta1::{
+    service_client::ServiceClient as TendermintServiceClient, GetLatestBlockRequest,
+    GetSyncingRequest,
+};
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, RwLock},
+    time::Duration,
+};
+use tonic::transport::{Channel, Endpoint};
+
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+lazy_static! {
+    /// Result of the last health check of each endpoint. Endpoints that haven't been checked are
+    /// treated as healthy.
+    static ref HEALTHY: RwLock<HashMap<String, bool>> = RwLock::new(HashMap::new());
+    static ref CHANNELS: Mutex<HashMap<String, Channel>> = Mutex::new(HashMap::new());
+}
+
+/// Every configured endpoint, in configured order
+pub fn configured() -> Vec<String> {
+    let config = APP.config();
+    std::iter::once(&config.cosmos.grpc)
+        .chain(config.cosmos.grpc_endpoints.iter())
+        .cloned()
+        .collect()
+}
+
+/// Every configured endpoint, healthy ones first, each group in configured order
+pub fn ordered() -> Vec<String> {
+    let healthy = HEALTHY.read().unwrap();
+    let (mut ordered, unhealthy): (Vec<String>, Vec<String>) = configured()
+        .into_iter()
+        .partition(|url| healthy.get(url).copied().unwrap_or(true));
+    ordered.extend(unhealthy);
+
+    ordered
+}
+
+/// The endpoint to use for the next request
+pub fn current() -> String {
+    ordered().remove(0)
+}
+
+/// Returns a long-lived channel to the current endpoint, creating it on first use. The connection
+/// is established lazily and re-established by tonic if it drops.
+pub fn get_channel() -> Result<Channel, Error> {
+    let url = current();
+    let mut channels = CHANNELS.lock().unwrap();
+    if let Some(c) = channels.get(&url) {
+        return Ok(c.clone());
+    }
+
+    let endpoint = Endpoint::from_shared(url.clone()).map_err(|e| {
+        ErrorKind::Config.context(format!("invalid cosmos grpc endpoint {}: {}", url, e))
+    })?;
+    let channel = endpoint.connect_lazy()?;
+    channels.insert(url, channel.clone());
+
+    Ok(channel)
+}
+
+/// Checks every endpoint now and returns the one to use. For commands that don't run
+/// [`health_check_loop`].
+pub async fn select() -> String {
+    check_all().await;
+
+    current()
+}
+
+/// Checks the endpoints every `cosmos.endpoint_health_check_interval` seconds. Runs until the
+/// process exits.
+pub async fn health_check_loop() {
+    let period = Duration::from_secs(APP.config().cosmos.endpoint_health_check_interval.max(1));
+    loop {
+        check_all().await;
+
+        tokio::time::sleep(period).await;
+    }
+}
+
+async fn check_all() {
+    for url in configured() {
+        let result = check(&url).await;
+        metrics::COSMOS_ENDPOINT_HEALTHY
+            .with_label_values(&[url.as_str()])
+            .set(if result.is_ok() { 1.0 } else { 0.0 });
+
+        let was_healthy = HEALTHY
+            .write()
+            .unwrap()
+            .insert(url.clone(), result.is_ok())
+            .unwrap_or(true);
+        match result {
+            Err(reason) if was_healthy => {
+                warn!("cosmos grpc endpoint {} is unhealthy: {}", url, reason)
+            }
+            Ok(()) if !was_healthy => info!("cosmos grpc endpoint {} is healthy again", url),
+            _ => (),
+        }
+    }
+}
+
+/// Returns the reason `url` shouldn't be used, if any
+async fn check(url: &str) -> Result<(), String> {
+    match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, query_health(url)).await {
+        Ok(result) => result,
+        Err(_) => Err("health check timed out".to_string()),
+    }
+}
+
+async fn query_health(url: &str) -> Result<(), String> {
+    let mut client = TendermintServiceClient::connect(url.to_string())
+        .await
+        .map_err(|e| format!("failed to connect: {}", e))?;
+
+    let syncing = client
+        .get_syncing(GetSyncingRequest {})
+        .await
+        .map_err(|e| format!("failed to query syncing status: {}", e))?
+        .into_inner()
+        .syncing;
+    if syncing {
+        return Err("node is syncing".to_string());
+    }
+
+    let block_time = client
+        .get_latest_block(GetLatestBlockRequest {})
+        .await
+        .map_err(|e| format!("failed to query latest block: {}", e))?
+        .into_inner()
+        .block
+        .and_then(|b| b.header)
+        .and_then(|h| h.time)
+        .ok_or_else(|| "latest block has no time".to_string())?;
+    let age = chrono::Utc::now().timestamp() - block_time.seconds;
+    let max_age = APP.config().cosmos.max_block_age as i64;
+    if age > max_age {
+        return Err(format!(
+            "latest block is {}s old, more than the maximum of {}s",
+            age, max_age
+        ));
+    }
+
+    Ok(())
+}
@@ -13,7 +13,7 @@ use std::{
     ops::Deref,
 };
 use thiserror::Error;
-use tonic::transport::Error as TonicError;
+use tonic::{transport::Error as TonicError, Status};
 
 /// Kinds of errors
 #[derive(Copy, Clone, Debug, Eq, Error, PartialEq)]
@@ -27,6 +27,9 @@ pub enum ErrorKind {
     /// Contract error
     #[error("contract error")]
     ContractError,
+    /// Audit database error
+    #[error("database error")]
+    Database,
     /// Input/output error
     #[error("I/O error")]
     Io,
@@ -39,15 +42,24 @@ pub enum ErrorKind {
     /// Input/output error
     #[error("http error")]
     Http,
+    /// Delegate account balance too low to pay fees
+    #[error("insufficient fee balance")]
+    InsufficientBalance,
     /// Cryptographic Keys error
     #[error("key related error")]
     KeysError,
     /// Provider error
     #[error("provider error")]
     ProviderError,
+    /// Transaction rejected by the node, e.g. when simulating it
+    #[error("transaction rejected")]
+    Rejected,
     /// Strategy Provider call error
     #[error("SP call error")]
     SPCallError,
+    /// Transaction may have been broadcast but was not seen in a block
+    #[error("unconfirmed transaction")]
+    Unconfirmed,
     /// Client error
     #[error("client error")]
     ClientError,
@@ -164,6 +176,18 @@ impl From<ProviderError> for Error {
     }
 }
 
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Self {
+        ErrorKind::Database.context(err).into()
+    }
+}
+
+impl From<Status> for Error {
+    fn from(err: Status) -> Self {
+        ErrorKind::GrpcError.context(err).into()
+    }
+}
+
 impl From<TonicError> for Error {
     fn from(err: TonicError) -> Self {
         let err: BoxError = err.into();
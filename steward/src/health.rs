@@ -0,0 +1,108 @@
+//! Readiness reporting through the standard `grpc.health.v1.Health` service
+//!
+//! The `ContractCallServer` service is reported as `SERVING` only while Steward could actually
+//! submit a cork: Sommelier is reachable and producing blocks, the delegate key loaded at startup,
+//! and the delegate account holds at least `cosmos.fee_balance_floor` of the fee denom. The server
+//! as a whole, the `""` service that health probes query by default, is reported with the same
+//! status.
+
+use crate::{cork::CorkHandler, cosmos_endpoints, prelude::APP};
+use abscissa_core::{
+    tracing::log::{info, warn},
+    Application,
+};
+use deep_space::{client::ChainStatus, Address, Contact};
+use std::time::Duration;
+use steward_proto::steward::contract_call_server::ContractCallServer;
+use tonic::transport::NamedService;
+use tonic_health::{server::HealthReporter, ServingStatus};
+
+/// How often readiness is re-evaluated
+const HEALTH_CHECK_PERIOD: Duration = Duration::from_secs(15);
+const HEALTH_QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Loads the delegate key once at startup and returns the delegate address, or the reason it
+/// could not be loaded to report as not serving
+pub fn load_delegate_address() -> Result<Address, String> {
+    let config = APP.config();
+    let key = config
+        .try_load_deep_space_key(config.keys.delegate_key.clone())
+        .map_err(|e| format!("failed to load delegate key: {}", e))?;
+
+    key.to_address(&config.cosmos.prefix)
+        .map_err(|e| format!("failed to derive delegate address: {}", e))
+}
+
+/// Periodically checks readiness and updates the health status of the `ContractCall` service. Runs
+/// until the process exits.
+pub async fn report_loop(mut reporter: HealthReporter, delegate_address: Result<Address, String>) {
+    let mut last: Option<Result<(), String>> = None;
+    loop {
+        let result = check_readiness(&delegate_address).await;
+        let status = match &result {
+            Ok(()) => ServingStatus::Serving,
+            Err(_) => ServingStatus::NotServing,
+        };
+        set_status(&mut reporter, status).await;
+        if last.as_ref() != Some(&result) {
+            match &result {
+                Ok(()) => info!("health status: serving"),
+                Err(reason) => warn!("health status: not serving: {}", reason),
+            }
+        }
+        last = Some(result);
+
+        tokio::time::sleep(HEALTH_CHECK_PERIOD).await;
+    }
+}
+
+/// Sets the status of the `ContractCall` service and of the server as a whole
+pub async fn set_status(reporter: &mut HealthReporter, status: ServingStatus) {
+    let service = <ContractCallServer<CorkHandler> as NamedService>::NAME;
+    reporter.set_service_status(service, status).await;
+    reporter.set_service_status("", status).await;
+}
+
+/// Returns the reason Steward cannot currently submit corks, if any
+async fn check_readiness(delegate_address: &Result<Address, String>) -> Result<(), String> {
+    let config = APP.config();
+    let grpc = cosmos_endpoints::current();
+    let contact = Contact::new(&grpc, HEALTH_QUERY_TIMEOUT, &config.cosmos.prefix)
+        .map_err(|e| format!("invalid cosmos grpc endpoint {}: {}", grpc, e))?;
+
+    match contact.get_chain_status().await {
+        Ok(ChainStatus::Moving { .. }) => (),
+        Ok(ChainStatus::Syncing) => return Err("sommelier node is syncing".to_string()),
+        Ok(ChainStatus::WaitingToStart) => {
+            return Err("sommelier chain has not started".to_string())
+        }
+        Err(err) => {
+            return Err(format!(
+                "sommelier grpc endpoint {} is unreachable: {}",
+                grpc, err
+            ))
+        }
+    }
+
+    let address = delegate_address.clone()?;
+
+    let denom = &config.cosmos.gas_price.denom;
+    let balances = contact
+        .get_balances(address)
+        .await
+        .map_err(|e| format!("failed to query delegate balance: {}", e))?;
+    let floor = config.cosmos.fee_balance_floor;
+    let sufficient = balances
+        .iter()
+        .find(|coin| &coin.denom == denom)
+        .map(|coin| coin.amount >= floor.into())
+        .unwrap_or(floor == 0);
+    if !sufficient {
+        return Err(format!(
+            "delegate balance of {} is below the fee balance floor of {}",
+            denom, floor
+        ));
+    }
+
+    Ok(())
+}
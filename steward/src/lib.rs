@@ -11,12 +11,16 @@
 #![allow(clippy::format_push_string)]
 
 pub mod application;
+pub mod audit;
 pub mod cellars;
 pub mod commands;
 pub mod config;
 pub mod cork;
+pub mod cosmos_endpoints;
 pub mod error;
 pub mod gas;
+pub mod health;
+pub mod metrics;
 pub mod prelude;
 pub mod server;
 pub mod somm_send;
@@ -0,0 +1,183 @@
+//! Prometheus metrics for the Steward server and cork pipeline
+//!
+//! Metrics are registered with the default registry and served as text from `metrics.listen_addr`
+//! while `steward start` is running.
+
+use crate::{
+    config,
+    cork::{cellar_ids, fee_balance},
+    cosmos_endpoints,
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{debug, error, info},
+    Application,
+};
+use deep_space::Contact;
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use lazy_static::lazy_static;
+use prometheus::{
+    register_counter, register_counter_vec, register_gauge, register_gauge_vec, register_histogram,
+    Counter, CounterVec, Encoder, Gauge, GaugeVec, Histogram, TextEncoder,
+};
+use std::{convert::Infallible, net::SocketAddr, time::Duration};
+
+/// How often the delegate account balance gauge is refreshed
+const BALANCE_REFRESH_PERIOD: Duration = Duration::from_secs(60);
+const BALANCE_QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Buckets of `SEND_CORK_DURATION`, in seconds. Sends wait for the transaction to be included
+/// and are retried with backoff, so they can take well over the default buckets' 10 seconds.
+const SEND_CORK_DURATION_BUCKETS: &[f64] = &[
+    0.5, 1.0, 2.5, 5.0, 7.5, 10.0, 15.0, 20.0, 30.0, 45.0, 60.0, 90.0,
+];
+
+lazy_static! {
+    pub static ref SUBMIT_REQUESTS: CounterVec = register_counter_vec!(
+        "steward_submit_requests_total",
+        "Submit requests handled, by cellar, function and gRPC status code",
+        &["cellar_id", "function", "code"]
+    )
+    .unwrap();
+    pub static ref SCHEDULE_REQUESTS: CounterVec = register_counter_vec!(
+        "steward_schedule_requests_total",
+        "Schedule requests handled, by cellar, function and gRPC status code",
+        &["cellar_id", "function", "code"]
+    )
+    .unwrap();
+    pub static ref GOVERNANCE_REJECTIONS: Counter = register_counter!(
+        "steward_governance_rejections_total",
+        "Submit requests rejected because the cellar is not approved by governance"
+    )
+    .unwrap();
+    pub static ref DUPLICATE_SUBMITS: Counter = register_counter!(
+        "steward_duplicate_submit_requests_total",
+        "Submit requests answered with the response to an earlier identical request"
+    )
+    .unwrap();
+    pub static ref RATE_LIMITED: CounterVec = register_counter_vec!(
+        "steward_rate_limited_requests_total",
+        "Requests rejected by a rate limit, by the limit that was exceeded",
+        &["limit"]
+    )
+    .unwrap();
+    pub static ref ENCODING_FAILURES: CounterVec = register_counter_vec!(
+        "steward_cork_encoding_failures_total",
+        "Submit requests whose contract call could not be encoded",
+        &["cellar_id"]
+    )
+    .unwrap();
+    pub static ref SEND_CORK_DURATION: Histogram = register_histogram!(
+        "steward_send_cork_duration_seconds",
+        "Time taken to sign and broadcast a cork transaction to Sommelier",
+        SEND_CORK_DURATION_BUCKETS.to_vec()
+    )
+    .unwrap();
+    pub static ref COSMOS_TX_FAILURES: CounterVec = register_counter_vec!(
+        "steward_cosmos_tx_failures_total",
+        "Cork transactions that were rejected by Sommelier, by result code",
+        &["code"]
+    )
+    .unwrap();
+    pub static ref DELEGATE_BALANCE: GaugeVec = register_gauge_vec!(
+        "steward_delegate_balance",
+        "Balance of the delegate account that pays cork transaction fees",
+        &["denom"]
+    )
+    .unwrap();
+    pub static ref COSMOS_ENDPOINT_HEALTHY: GaugeVec = register_gauge_vec!(
+        "steward_cosmos_endpoint_healthy",
+        "1 if the Sommelier gRPC endpoint passed its last health check",
+        &["endpoint"]
+    )
+    .unwrap();
+    pub static ref DELEGATE_BALANCE_LOW: Gauge = register_gauge!(
+        "steward_delegate_balance_low",
+        "1 if the delegate account fee balance is below cosmos.fee_balance_warning_threshold"
+    )
+    .unwrap();
+}
+
+/// Label for `cellar_id` in metrics. Only governance approved cellar IDs are used as labels so that
+/// clients can't create an unbounded number of series.
+pub fn cellar_label(cellar_id: &str) -> &str {
+    if cellar_ids::is_cached(cellar_id) {
+        cellar_id
+    } else {
+        "unapproved"
+    }
+}
+
+/// Serves the metrics in the Prometheus text format on `addr` until the server fails
+pub async fn serve(addr: SocketAddr) -> Result<(), Error> {
+    let make_service = make_service_fn(|_| async { Ok::<_, Infallible>(service_fn(handle)) });
+
+    info!("serving metrics on {}", addr);
+    Server::try_bind(&addr)
+        .map_err(|e| ErrorKind::Http.context(e))?
+        .serve(make_service)
+        .await
+        .map_err(|e| ErrorKind::Http.context(e).into())
+}
+
+async fn handle(request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::GET || request.uri().path() != "/metrics" {
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        return Ok(response);
+    }
+
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    if let Err(err) = encoder.encode(&prometheus::gather(), &mut buffer) {
+        error!("failed to encode metrics: {}", err);
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        return Ok(response);
+    }
+
+    let mut response = Response::new(Body::from(buffer));
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        encoder
+            .format_type()
+            .parse()
+            .expect("prometheus format type is a valid header value"),
+    );
+    Ok(response)
+}
+
+/// Periodically updates the delegate balance gauge. Runs until the process exits.
+pub async fn refresh_delegate_balance() {
+    loop {
+        if let Err(err) = update_delegate_balance().await {
+            error!("failed to update delegate balance metric: {}", err);
+        }
+
+        tokio::time::sleep(BALANCE_REFRESH_PERIOD).await;
+    }
+}
+
+async fn update_delegate_balance() -> Result<(), Error> {
+    let config = APP.config();
+    let contact = Contact::new(
+        &cosmos_endpoints::current(),
+        BALANCE_QUERY_TIMEOUT,
+        &config.cosmos.prefix,
+    )?;
+    let balances = contact.get_balances(*config::DELEGATE_ADDRESS).await?;
+    fee_balance::observe(&balances);
+    for coin in balances {
+        debug!("delegate balance: {}{}", coin.amount, coin.denom);
+        let amount = coin.amount.to_string().parse::<f64>().unwrap_or(f64::MAX);
+        DELEGATE_BALANCE
+            .with_label_values(&[coin.denom.as_str()])
+            .set(amount);
+    }
+
+    Ok(())
+}
@@ -1,37 +1,102 @@
 use std::net::SocketAddr;
 
 use crate::{config::StewardConfig, error::Error};
-use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+use sha2::{Digest, Sha256};
+use tonic::Request;
+use x509_parser::{
+    objects::{oid2abbrev, oid_registry},
+    parse_x509_certificate,
+    x509::X509Name,
+};
+
+pub mod authorization;
+pub mod rate_limit;
+pub mod revocation;
+pub mod tls;
 
 pub const DEFAULT_CLIENT_CA: &[u8] = include_bytes!("../../tls/sevenseas_ca.crt");
 // for gRPC reflection
 pub const DESCRIPTOR: &[u8] = include_bytes!("../../steward_proto_rust/src/prost/descriptor.bin");
 
 pub struct ServerConfig {
-    pub tls_config: ServerTlsConfig,
+    pub tls: tls::ReloadableTls,
     pub address: SocketAddr,
 }
 
-pub async fn load_server_config(
-    config: &std::sync::Arc<StewardConfig>,
-) -> Result<ServerConfig, Error> {
-    let cert = tokio::fs::read(&config.server.server_cert_path).await?;
-    let key = tokio::fs::read(&config.server.server_key_path).await?;
-    let server_identity = Identity::from_pem(cert, key);
-    let client_ca = match &config.server.client_ca_cert_path {
-        Some(path) => tokio::fs::read(path).await?,
-        None => DEFAULT_CLIENT_CA.into(),
-    };
-    let client_ca_cert = Certificate::from_pem(client_ca);
-    let tls_config = ServerTlsConfig::new()
-        .identity(server_identity.clone())
-        .client_ca_root(client_ca_cert);
+pub fn load_server_config(config: &std::sync::Arc<StewardConfig>) -> Result<ServerConfig, Error> {
+    let tls = tls::ReloadableTls::new(&config.server)?;
     let port = &config.server.port;
     let address = &config.server.address;
     let address: SocketAddr = format!("{}:{}", address, port).parse()?;
 
-    Ok(ServerConfig {
-        tls_config,
-        address,
-    })
+    Ok(ServerConfig { tls, address })
+}
+
+/// Identity of a gRPC client taken from the leaf certificate it presented during the TLS handshake
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClientIdentity {
+    /// Distinguished name of the certificate subject in certificate order, e.g.
+    /// `O=Example,CN=strategist`. Empty if the certificate could not be parsed.
+    pub subject: String,
+    /// Hex-encoded SHA-256 fingerprint of the DER encoded certificate
+    pub fingerprint: String,
+}
+
+/// Returns the identity of the client that sent `request`, or `None` if it did not present a
+/// certificate
+pub fn get_client_identity<T>(request: &Request<T>) -> Option<ClientIdentity> {
+    let certs = request.peer_certs()?;
+    let der = certs.first()?.get_ref();
+
+    Some(get_certificate_identity(der))
+}
+
+/// Builds the identity of a DER encoded certificate
+pub fn get_certificate_identity(der: &[u8]) -> ClientIdentity {
+    let subject = match parse_x509_certificate(der) {
+        Ok((_, cert)) => format_name(cert.subject()),
+        Err(_) => String::new(),
+    };
+
+    ClientIdentity {
+        subject,
+        fingerprint: hex::encode(Sha256::digest(der)),
+    }
+}
+
+/// Formats `name` like RFC 4514 but in certificate order, escaping the characters that separate
+/// attributes. Values that aren't strings are written as `#` followed by their hex encoding.
+fn format_name(name: &X509Name) -> String {
+    name.iter_rdn()
+        .map(|rdn| {
+            rdn.iter()
+                .map(|attr| {
+                    let key = match oid2abbrev(attr.attr_type(), oid_registry()) {
+                        Ok(abbrev) => abbrev.to_string(),
+                        Err(_) => attr.attr_type().to_id_string(),
+                    };
+                    let value = match attr.as_str() {
+                        Ok(value) => escape_name_value(value),
+                        Err(_) => format!("#{}", hex::encode(attr.as_slice().unwrap_or_default())),
+                    };
+
+                    format!("{}={}", key, value)
+                })
+                .collect::<Vec<String>>()
+                .join("+")
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn escape_name_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, ',' | '+' | '"' | '\\' | '<' | '>' | ';') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
 }
@@ -0,0 +1,284 @@
+//! Per-client authorization of `Submit`, `Schedule` and `ListScheduledCorks` requests
+//!
+//! Policies from `[server.authorization]` map client certificates, by subject or SHA-256
+//! fingerprint, to the cellar IDs and functions they may submit corks for. A request is allowed if
+//! a single policy matching the client covers the cellar and every function the call executes.
+//! A client may list the scheduled corks of the cellars its policies cover.
+//!
+//! Subjects are compared as sets of attributes, so the order of the attributes, the spacing
+//! around separators and the case of the attribute types and values don't matter.
+
+use crate::config::AuthorizationPolicy;
+use std::collections::BTreeSet;
+
+use super::ClientIdentity;
+
+const WILDCARD: &str = "*";
+
+/// Checks whether `identity` may invoke `functions` on `cellar_id` under `policies`, returning the
+/// reason if not. Every request is allowed when no policies are configured.
+pub fn authorize(
+    policies: &[AuthorizationPolicy],
+    identity: Option<&ClientIdentity>,
+    cellar_id: &str,
+    functions: &[String],
+) -> Result<(), String> {
+    if policies.is_empty() {
+        return Ok(());
+    }
+
+    let identity = get_identity(identity)?;
+    let client_policies = get_client_policies(policies, identity)?;
+    let allowed = client_policies
+        .iter()
+        .any(|p| matches_cellar(p, cellar_id) && functions.iter().all(|f| matches_function(p, f)));
+    if !allowed {
+        return Err(format!(
+            "client {} is not authorized to call {} on cellar {}",
+            identity.subject,
+            functions.join(", "),
+            cellar_id
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks whether `identity` may list scheduled corks under `policies`, returning the reason if
+/// not. Clients may list corks if any policy matches them.
+pub fn authorize_listing(
+    policies: &[AuthorizationPolicy],
+    identity: Option<&ClientIdentity>,
+) -> Result<(), String> {
+    if policies.is_empty() {
+        return Ok(());
+    }
+
+    get_client_policies(policies, get_identity(identity)?).map(|_| ())
+}
+
+/// Returns whether `identity` may see the scheduled corks for `cellar_id`, which it may if a policy
+/// matching it covers the cellar for any function
+pub fn may_view_cellar(
+    policies: &[AuthorizationPolicy],
+    identity: Option<&ClientIdentity>,
+    cellar_id: &str,
+) -> bool {
+    if policies.is_empty() {
+        return true;
+    }
+
+    match identity {
+        Some(identity) => policies
+            .iter()
+            .any(|p| matches_client(p, identity) && matches_cellar(p, cellar_id)),
+        None => false,
+    }
+}
+
+fn get_identity(identity: Option<&ClientIdentity>) -> Result<&ClientIdentity, String> {
+    identity.ok_or_else(|| "client did not present a certificate".to_string())
+}
+
+fn get_client_policies<'a>(
+    policies: &'a [AuthorizationPolicy],
+    identity: &ClientIdentity,
+) -> Result<Vec<&'a AuthorizationPolicy>, String> {
+    let client_policies: Vec<&AuthorizationPolicy> = policies
+        .iter()
+        .filter(|p| matches_client(p, identity))
+        .collect();
+    if client_policies.is_empty() {
+        return Err(format!(
+            "no authorization policy for client {} ({})",
+            identity.subject, identity.fingerprint
+        ));
+    }
+
+    Ok(client_policies)
+}
+
+fn matches_client(policy: &AuthorizationPolicy, identity: &ClientIdentity) -> bool {
+    let subject = parse_name(&identity.subject);
+    if subject.is_some() && policy.subjects.iter().any(|s| parse_name(s) == subject) {
+        return true;
+    }
+
+    policy
+        .fingerprints
+        .iter()
+        .any(|f| normalize_fingerprint(f) == identity.fingerprint)
+}
+
+fn matches_cellar(policy: &AuthorizationPolicy, cellar_id: &str) -> bool {
+    policy
+        .cellar_ids
+        .iter()
+        .any(|c| c == WILDCARD || c.eq_ignore_ascii_case(cellar_id))
+}
+
+fn matches_function(policy: &AuthorizationPolicy, function: &str) -> bool {
+    policy
+        .functions
+        .iter()
+        .any(|f| f == WILDCARD || f == function)
+}
+
+/// Parses a distinguished name such as `CN=strategist,O=Example` into its attributes, with
+/// uppercased types and lowercased values. Separators within values must be escaped with a
+/// backslash. Returns `None` if the name is empty or malformed.
+fn parse_name(name: &str) -> Option<BTreeSet<(String, String)>> {
+    let mut attributes = BTreeSet::new();
+    let mut attribute = String::new();
+    let mut chars = name.chars();
+    loop {
+        let c = chars.next();
+        match c {
+            Some('\\') => {
+                attribute.push('\\');
+                attribute.push(chars.next()?);
+                continue;
+            }
+            Some(',') | Some('+') | None => (),
+            Some(c) => {
+                attribute.push(c);
+                continue;
+            }
+        }
+
+        let (key, value) = attribute.split_once('=')?;
+        let key = match key.trim().to_uppercase().as_str() {
+            "" => return None,
+            "E" | "EMAILADDRESS" => "EMAIL".to_string(),
+            k => k.to_string(),
+        };
+        attributes.insert((key, unescape(value.trim()).to_lowercase()));
+        attribute.clear();
+
+        if c.is_none() {
+            return Some(attributes);
+        }
+    }
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+/// Lowercases a hex fingerprint and strips the colons some tools print between bytes
+fn normalize_fingerprint(fingerprint: &str) -> String {
+    fingerprint.replace(':', "").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{get_certificate_identity, DEFAULT_CLIENT_CA};
+    use rustls::internal::pemfile::certs;
+
+    fn policy(subject: &str, cellar_id: &str, functions: &[&str]) -> AuthorizationPolicy {
+        AuthorizationPolicy {
+            subjects: vec![subject.to_string()],
+            cellar_ids: vec![cellar_id.to_string()],
+            functions: functions.iter().map(|f| f.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn authorize_enforces_cellar_and_functions() {
+        let policies = vec![
+            policy("CN=sp1", "0xAAAA", &["rebalance"]),
+            policy("CN=sp2", "0xbbbb", &["*"]),
+        ];
+        let identity = ClientIdentity {
+            subject: "CN=sp1".to_string(),
+            fingerprint: "ab".to_string(),
+        };
+        let rebalance = vec!["rebalance".to_string()];
+
+        assert!(authorize(&policies, Some(&identity), "0xaaaa", &rebalance).is_ok());
+        assert!(authorize(&policies, Some(&identity), "0xbbbb", &rebalance).is_err());
+        let batch = vec!["rebalance".to_string(), "sendFees".to_string()];
+        assert!(authorize(&policies, Some(&identity), "0xaaaa", &batch).is_err());
+        assert!(authorize(&policies, None, "0xaaaa", &rebalance).is_err());
+        assert!(authorize(&[], None, "0xaaaa", &rebalance).is_ok());
+    }
+
+    #[test]
+    fn listing_is_limited_to_covered_cellars() {
+        let policies = vec![policy("CN=sp1", "0xAAAA", &["rebalance"])];
+        let sp1 = ClientIdentity {
+            subject: "CN=sp1".to_string(),
+            fingerprint: "ab".to_string(),
+        };
+        let sp2 = ClientIdentity {
+            subject: "CN=sp2".to_string(),
+            fingerprint: "cd".to_string(),
+        };
+
+        assert!(authorize_listing(&policies, Some(&sp1)).is_ok());
+        assert!(authorize_listing(&policies, Some(&sp2)).is_err());
+        assert!(authorize_listing(&policies, None).is_err());
+        assert!(may_view_cellar(&policies, Some(&sp1), "0xaaaa"));
+        assert!(!may_view_cellar(&policies, Some(&sp1), "0xbbbb"));
+        assert!(may_view_cellar(&[], None, "0xbbbb"));
+    }
+
+    #[test]
+    fn authorize_matches_certificate_subject() {
+        let der = certs(&mut &DEFAULT_CLIENT_CA[..]).unwrap().remove(0);
+        let identity = get_certificate_identity(&der.0);
+        let accrue = vec!["accrue".to_string()];
+
+        assert_eq!(
+            identity.subject,
+            "C=AU,ST=Some-State,O=Seven Seas Strategy Inc"
+        );
+        for subject in [
+            "O=Seven Seas Strategy Inc,ST=Some-State,C=AU",
+            "c=AU, st=some-state, o=Seven Seas Strategy Inc",
+        ] {
+            let policies = vec![policy(subject, "*", &["*"])];
+            assert!(authorize(&policies, Some(&identity), "0xaaaa", &accrue).is_ok());
+        }
+        let policies = vec![policy("O=Seven Seas Strategy Inc", "*", &["*"])];
+        assert!(authorize(&policies, Some(&identity), "0xaaaa", &accrue).is_err());
+    }
+
+    #[test]
+    fn parse_name_handles_escapes() {
+        let name = parse_name(r"CN=Example\, Inc.+OU=ops").unwrap();
+
+        assert!(name.contains(&("CN".to_string(), "example, inc.".to_string())));
+        assert!(name.contains(&("OU".to_string(), "ops".to_string())));
+        assert_eq!(parse_name(""), None);
+        assert_eq!(parse_name("CN=a,"), None);
+    }
+
+    #[test]
+    fn authorize_matches_fingerprint() {
+        let policies = vec![AuthorizationPolicy {
+            fingerprints: vec!["AB:CD".to_string()],
+            cellar_ids: vec!["*".to_string()],
+            functions: vec!["*".to_string()],
+            ..Default::default()
+        }];
+        let identity = ClientIdentity {
+            subject: String::new(),
+            fingerprint: "abcd".to_string(),
+        };
+        let accrue = vec!["accrue".to_string()];
+
+        assert!(authorize(&policies, Some(&identity), "0xaaaa", &accrue).is_ok());
+    }
+}
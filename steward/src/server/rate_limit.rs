@@ -0,0 +1,179 @@
+//! Rate limiting of `ContractCall` requests per client certificate and of corks per cellar
+//!
+//! Every accepted cork costs the delegate account Cosmos fees, so a client stuck in a loop could
+//! drain it. Each client and each cellar gets a token bucket that refills at
+//! `requests_per_minute` and holds at most `burst` requests. The client limit is applied to every
+//! RPC by [`ClientRateLimitLayer`], the cellar limit once a cork has been approved.
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use abscissa_core::{tracing::log::info, Application};
+use futures::future::{self, Either, Ready};
+use hyper::{Request, Response};
+use lazy_static::lazy_static;
+use tonic::{body::BoxBody, transport::NamedService, Status};
+use tower::{Layer, Service};
+
+use crate::{config::RateLimit, metrics, prelude::APP};
+
+/// Buckets untouched for this long are full again and can be forgotten
+const IDLE_BUCKET_EXPIRY: Duration = Duration::from_secs(3600);
+/// Number of buckets above which idle ones are pruned
+const PRUNE_THRESHOLD: usize = 1024;
+
+lazy_static! {
+    pub static ref CLIENT_LIMITER: RateLimiter =
+        RateLimiter::new(&APP.config().server.rate_limit.client);
+    pub static ref CELLAR_LIMITER: RateLimiter =
+        RateLimiter::new(&APP.config().server.rate_limit.cellar);
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// A set of token buckets keyed by client or cellar
+pub struct RateLimiter {
+    /// Tokens added per second
+    rate: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: &RateLimit) -> Self {
+        RateLimiter {
+            rate: f64::from(limit.requests_per_minute) / 60.0,
+            burst: f64::from(limit.burst.max(1)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Takes a token from the bucket for `key`, returning false if it is empty. Always returns
+    /// true if the limit is disabled.
+    pub fn check(&self, key: &str) -> bool {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&self, key: &str, now: Instant) -> bool {
+        if self.rate <= 0.0 {
+            return true;
+        }
+
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() > PRUNE_THRESHOLD {
+            buckets.retain(|_, b| now.duration_since(b.updated_at) < IDLE_BUCKET_EXPIRY);
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: self.burst,
+            updated_at: now,
+        });
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.burst);
+        bucket.updated_at = now;
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+
+        bucket.tokens -= 1.0;
+        true
+    }
+}
+
+/// Applies [`CLIENT_LIMITER`] to every request on a connection. tonic doesn't expose the peer
+/// certificates to tower services, so the layer is built per connection with the fingerprint of
+/// the certificate presented during the handshake.
+#[derive(Clone, Debug)]
+pub struct ClientRateLimitLayer {
+    client: String,
+}
+
+impl ClientRateLimitLayer {
+    pub fn new(client: String) -> Self {
+        ClientRateLimitLayer { client }
+    }
+}
+
+impl<S> Layer<S> for ClientRateLimitLayer {
+    type Service = ClientRateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClientRateLimit {
+            inner,
+            client: self.client.clone(),
+        }
+    }
+}
+
+/// Service built by [`ClientRateLimitLayer`]
+#[derive(Clone, Debug)]
+pub struct ClientRateLimit<S> {
+    inner: S,
+    client: String,
+}
+
+impl<S, B> Service<Request<B>> for ClientRateLimit<S>
+where
+    S: Service<Request<B>, Response = Response<BoxBody>>,
+{
+    type Response = Response<BoxBody>;
+    type Error = S::Error;
+    type Future = Either<Ready<Result<Response<BoxBody>, S::Error>>, S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        if !CLIENT_LIMITER.check(&self.client) {
+            info!("rate limiting client {}", self.client);
+            metrics::RATE_LIMITED.with_label_values(&["client"]).inc();
+            let status = Status::resource_exhausted("client request rate limit exceeded");
+            return Either::Left(future::ready(Ok(status.to_http())));
+        }
+
+        Either::Right(self.inner.call(request))
+    }
+}
+
+impl<S: NamedService> NamedService for ClientRateLimit<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limiter_allows_burst_then_refills() {
+        let limiter = RateLimiter::new(&RateLimit {
+            requests_per_minute: 60,
+            burst: 2,
+        });
+        let start = Instant::now();
+
+        assert!(limiter.check_at("a", start));
+        assert!(limiter.check_at("a", start));
+        assert!(!limiter.check_at("a", start));
+        assert!(limiter.check_at("b", start));
+        assert!(limiter.check_at("a", start + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn zero_rate_disables_limit() {
+        let limiter = RateLimiter::new(&RateLimit {
+            requests_per_minute: 0,
+            burst: 0,
+        });
+        let start = Instant::now();
+
+        assert!((0..100).all(|_| limiter.check_at("a", start)));
+    }
+}
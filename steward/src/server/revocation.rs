@@ -0,0 +1,215 @@
+//! Revocation checks for strategy provider client certificates
+//!
+//! Revocations are read from the files in `server.revocation_dir`:
+//!
+//! - `*.crl` files hold a certificate revocation list, PEM or DER encoded. A client certificate is
+//!   rejected if its serial number is listed in a CRL from its issuer.
+//! - `*.deny` files list hex SHA-256 certificate fingerprints, one per line. Blank lines and lines
+//!   starting with `#` are ignored.
+//!
+//! Other files are ignored. A CRL that can't be read or parsed is skipped with an error. The
+//! directory is re-read whenever the TLS material is reloaded.
+//!
+//! Certificates are checked during the TLS handshake and again on every request by [`intercept`],
+//! so a client that is revoked while it holds a connection is rejected without reconnecting.
+
+use std::{
+    collections::HashSet,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+
+use abscissa_core::tracing::log::{error, info, warn};
+use lazy_static::lazy_static;
+use rustls::{Certificate, ClientCertVerified, ClientCertVerifier, DistinguishedNames, TLSError};
+use sha2::{Digest, Sha256};
+use tonic::{Request, Status};
+use x509_parser::{parse_x509_certificate, parse_x509_crl, pem::parse_x509_pem};
+
+use crate::error::{Error, ErrorKind};
+
+lazy_static! {
+    /// Revocations loaded with the TLS acceptor currently in use
+    static ref CURRENT: RwLock<Arc<RevocationList>> = RwLock::new(Arc::new(RevocationList::default()));
+}
+
+/// Certificates that must not be accepted even though they chain to a trusted CA
+#[derive(Clone, Debug, Default)]
+pub struct RevocationList {
+    /// Issuer and hex serial number of each certificate revoked by a CRL
+    revoked: HashSet<(String, String)>,
+    /// Lowercase hex SHA-256 fingerprints from the deny lists
+    denied: HashSet<String>,
+}
+
+impl RevocationList {
+    /// Loads the CRLs and deny lists in `dir`
+    pub fn load(dir: &str) -> Result<Self, Error> {
+        let mut list = RevocationList::default();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            match path.extension().and_then(|e| e.to_str()) {
+                Some("crl") => {
+                    let result = std::fs::read(&path)
+                        .map_err(Error::from)
+                        .and_then(|data| list.add_crl(&data, &path));
+                    if let Err(err) = result {
+                        error!("skipping CRL {}: {}", path.display(), err);
+                    }
+                }
+                Some("deny") => list.add_deny_list(&std::fs::read_to_string(&path)?),
+                _ => continue,
+            }
+        }
+        info!(
+            "loaded {} revoked serials and {} denied fingerprints from {}",
+            list.revoked.len(),
+            list.denied.len(),
+            dir
+        );
+
+        Ok(list)
+    }
+
+    fn add_crl(&mut self, data: &[u8], path: &Path) -> Result<(), Error> {
+        let invalid =
+            |e: String| ErrorKind::Config.context(format!("invalid CRL {}: {}", path.display(), e));
+        let pem;
+        let der = if data.starts_with(b"-----BEGIN") {
+            pem = parse_x509_pem(data).map_err(|e| invalid(e.to_string()))?.1;
+            &pem.contents
+        } else {
+            data
+        };
+
+        let (_, crl) = parse_x509_crl(der).map_err(|e| invalid(e.to_string()))?;
+        let issuer = crl.issuer().to_string();
+        for revoked in crl.iter_revoked_certificates() {
+            self.revoked
+                .insert((issuer.clone(), revoked.serial().to_str_radix(16)));
+        }
+
+        Ok(())
+    }
+
+    fn add_deny_list(&mut self, contents: &str) {
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            self.denied.insert(line.replace(':', "").to_lowercase());
+        }
+    }
+
+    /// Returns the reason the DER encoded certificate is revoked, if it is
+    pub fn check(&self, der: &[u8]) -> Option<String> {
+        let fingerprint = hex::encode(Sha256::digest(der));
+        if self.denied.contains(&fingerprint) {
+            return Some(format!("certificate {} is on a deny list", fingerprint));
+        }
+
+        if let Ok((_, cert)) = parse_x509_certificate(der) {
+            let issuer = cert.issuer().to_string();
+            let serial = cert.tbs_certificate.serial.to_str_radix(16);
+            if self.revoked.contains(&(issuer, serial.clone())) {
+                return Some(format!(
+                    "certificate {} with serial {} has been revoked by its issuer",
+                    fingerprint, serial
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+/// Replaces the revocations that requests are checked against
+pub fn set_current(list: RevocationList) {
+    *CURRENT.write().unwrap() = Arc::new(list);
+}
+
+/// gRPC interceptor that rejects requests from clients whose certificate is revoked by the current
+/// revocations
+pub fn intercept(request: Request<()>) -> Result<Request<()>, Status> {
+    let list = CURRENT.read().unwrap().clone();
+    let certs = request.peer_certs();
+    if let Some(reason) = certs
+        .as_ref()
+        .and_then(|c| c.first())
+        .and_then(|c| list.check(c.get_ref()))
+    {
+        warn!("rejecting request: {}", reason);
+        return Err(Status::unauthenticated(
+            "client certificate has been revoked",
+        ));
+    }
+
+    Ok(request)
+}
+
+/// Verifies client certificates with `inner` and then rejects any that have been revoked
+pub struct RevocationCheckingVerifier {
+    pub inner: Arc<dyn ClientCertVerifier>,
+    pub revocations: RevocationList,
+}
+
+impl ClientCertVerifier for RevocationCheckingVerifier {
+    fn offer_client_auth(&self) -> bool {
+        self.inner.offer_client_auth()
+    }
+
+    fn client_auth_mandatory(&self, sni: Option<&webpki::DNSName>) -> Option<bool> {
+        self.inner.client_auth_mandatory(sni)
+    }
+
+    fn client_auth_root_subjects(
+        &self,
+        sni: Option<&webpki::DNSName>,
+    ) -> Option<DistinguishedNames> {
+        self.inner.client_auth_root_subjects(sni)
+    }
+
+    fn verify_client_cert(
+        &self,
+        presented_certs: &[Certificate],
+        sni: Option<&webpki::DNSName>,
+    ) -> Result<ClientCertVerified, TLSError> {
+        let verified = self.inner.verify_client_cert(presented_certs, sni)?;
+        if let Some(reason) = presented_certs
+            .first()
+            .and_then(|c| self.revocations.check(&c.0))
+        {
+            warn!("rejecting client certificate: {}", reason);
+            return Err(TLSError::General(reason));
+        }
+
+        Ok(verified)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deny_list_matches_fingerprint() {
+        let der = b"not a real certificate";
+        let fingerprint = hex::encode(Sha256::digest(der));
+        let mut list = RevocationList::default();
+        list.add_deny_list(&format!("# leaked\n\n{}\n", fingerprint.to_uppercase()));
+
+        assert!(list.check(der).is_some());
+        assert!(list.check(b"another certificate").is_none());
+    }
+
+    #[test]
+    fn load_skips_malformed_crl() {
+        let dir = tempdir::TempDir::new("revocation").unwrap();
+        std::fs::write(dir.path().join("bad.crl"), b"not a crl").unwrap();
+        std::fs::write(dir.path().join("leaked.deny"), "ab:cd\n").unwrap();
+        let list = RevocationList::load(dir.path().to_str().unwrap()).unwrap();
+
+        assert!(list.denied.contains("abcd"));
+    }
+}
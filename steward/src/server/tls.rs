@@ -0,0 +1,288 @@
+//! TLS for the Steward server with reloadable certificates
+//!
+//! Connections are accepted on a plain TCP listener and the TLS handshake is performed with the
+//! acceptor current at the time, so replacing the acceptor rotates the server certificate and
+//! client CA bundles without dropping the listener or established connections. The acceptor is
+//! rebuilt on SIGHUP, and whenever one of the configured files or revocation files changes on disk.
+
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    net::SocketAddr,
+    path::Path,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use abscissa_core::tracing::log::{debug, error, info, warn};
+use futures::Stream;
+use rustls::{
+    internal::pemfile::{certs, pkcs8_private_keys, rsa_private_keys},
+    AllowAnyAuthenticatedClient, ClientCertVerifier, RootCertStore, ServerConfig,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    signal::unix::{signal, SignalKind},
+    sync::mpsc,
+};
+use tokio_rustls::{server::TlsStream, TlsAcceptor};
+
+use crate::{
+    config::ServerSection,
+    error::{Error, ErrorKind},
+};
+
+use super::{
+    revocation::{self, RevocationCheckingVerifier, RevocationList},
+    DEFAULT_CLIENT_CA,
+};
+
+/// Time allowed for a client to complete the TLS handshake
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Number of completed handshakes that may wait to be picked up by the gRPC server
+const INCOMING_BUFFER: usize = 64;
+/// Delay before accepting again after the first failure, such as running out of file descriptors
+const ACCEPT_BACKOFF_MIN: Duration = Duration::from_millis(10);
+/// Delay between accept attempts while failures persist
+const ACCEPT_BACKOFF_MAX: Duration = Duration::from_secs(1);
+
+/// The TLS acceptor used for new connections, shared between the listener and the reloader
+#[derive(Clone)]
+pub struct ReloadableTls {
+    acceptor: Arc<RwLock<TlsAcceptor>>,
+    server: ServerSection,
+}
+
+impl ReloadableTls {
+    /// Loads the TLS material configured in `server`
+    pub fn new(server: &ServerSection) -> Result<Self, Error> {
+        let (acceptor, revocations) = load_acceptor(server)?;
+        revocation::set_current(revocations);
+
+        Ok(ReloadableTls {
+            acceptor: Arc::new(RwLock::new(acceptor)),
+            server: server.clone(),
+        })
+    }
+
+    /// Reloads the TLS material from disk. The current acceptor is kept if loading fails.
+    pub fn reload(&self) -> Result<(), Error> {
+        let (acceptor, revocations) = load_acceptor(&self.server)?;
+        *self.acceptor.write().unwrap() = acceptor;
+        revocation::set_current(revocations);
+
+        Ok(())
+    }
+
+    fn acceptor(&self) -> TlsAcceptor {
+        self.acceptor.read().unwrap().clone()
+    }
+
+    /// Paths of every file the TLS material is loaded from
+    fn paths(&self) -> Vec<String> {
+        let mut paths = vec![
+            self.server.server_cert_path.clone(),
+            self.server.server_key_path.clone(),
+        ];
+        paths.extend(client_ca_paths(&self.server));
+        if let Some(dir) = &self.server.revocation_dir {
+            // The directory itself changes when revocation files are added or removed
+            paths.push(dir.clone());
+            if let Ok(entries) = std::fs::read_dir(dir) {
+                paths.extend(entries.filter_map(|e| Some(e.ok()?.path().to_str()?.to_string())));
+            }
+        }
+
+        paths
+    }
+
+    /// Binds `address` and returns the stream of connections that completed the TLS handshake
+    pub async fn incoming(
+        &self,
+        address: SocketAddr,
+    ) -> Result<impl Stream<Item = Result<TlsStream<TcpStream>, io::Error>>, Error> {
+        let listener = TcpListener::bind(address).await?;
+        let (tx, rx) = mpsc::channel(INCOMING_BUFFER);
+        let tls = self.clone();
+        tokio::spawn(async move {
+            let mut backoff = ACCEPT_BACKOFF_MIN;
+            loop {
+                let accepted = tokio::select! {
+                    accepted = listener.accept() => accepted,
+                    // The gRPC server has stopped
+                    _ = tx.closed() => break,
+                };
+                let (stream, peer) = match accepted {
+                    Ok(s) => {
+                        backoff = ACCEPT_BACKOFF_MIN;
+                        s
+                    }
+                    Err(err) => {
+                        error!(
+                            "failed to accept connection, retrying in {:?}: {}",
+                            backoff, err
+                        );
+                        tokio::time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(ACCEPT_BACKOFF_MAX);
+                        continue;
+                    }
+                };
+
+                // Handshakes run concurrently so a slow client doesn't hold up the listener
+                let acceptor = tls.acceptor();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                        Ok(Ok(stream)) => {
+                            let _ = tx.send(stream).await;
+                        }
+                        Ok(Err(err)) => debug!("TLS handshake with {} failed: {}", peer, err),
+                        Err(_) => debug!("TLS handshake with {} timed out", peer),
+                    }
+                });
+            }
+        });
+
+        Ok(futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|stream| (Ok(stream), rx))
+        }))
+    }
+
+    /// Reloads the TLS material on SIGHUP, and when a file it was loaded from is modified if
+    /// `server.tls_reload_interval` is nonzero. Runs until the process exits.
+    pub async fn reload_loop(self) {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(s) => Some(s),
+            Err(err) => {
+                warn!(
+                    "failed to listen for SIGHUP, TLS reload on signal disabled: {}",
+                    err
+                );
+                None
+            }
+        };
+        let poll_period = Duration::from_secs(self.server.tls_reload_interval);
+        let mut modified = get_modified_times(&self.paths());
+        loop {
+            let hangup_received = async {
+                match hangup.as_mut() {
+                    Some(s) if s.recv().await.is_some() => (),
+                    _ => futures::future::pending().await,
+                }
+            };
+            let poll_elapsed = async {
+                if poll_period.is_zero() {
+                    futures::future::pending().await
+                } else {
+                    tokio::time::sleep(poll_period).await
+                }
+            };
+            let signaled = tokio::select! {
+                _ = hangup_received => true,
+                _ = poll_elapsed => false,
+            };
+
+            let current = get_modified_times(&self.paths());
+            if !signaled && current == modified {
+                continue;
+            }
+            modified = current;
+
+            match self.reload() {
+                Ok(()) => info!("reloaded TLS certificates"),
+                Err(err) => error!(
+                    "failed to reload TLS certificates, keeping current: {}",
+                    err
+                ),
+            }
+        }
+    }
+}
+
+/// Client CA bundle paths from `client_ca_cert_path` and `client_ca_cert_paths`
+pub fn client_ca_paths(server: &ServerSection) -> Vec<String> {
+    server
+        .client_ca_cert_path
+        .iter()
+        .chain(server.client_ca_cert_paths.iter())
+        .cloned()
+        .collect()
+}
+
+/// Builds the acceptor for the TLS material configured in `server`, returning it with the
+/// revocations its client certificate verifier enforces
+fn load_acceptor(server: &ServerSection) -> Result<(TlsAcceptor, RevocationList), Error> {
+    let mut roots = RootCertStore::empty();
+    let ca_paths = client_ca_paths(server);
+    if ca_paths.is_empty() {
+        add_client_cas(&mut roots, DEFAULT_CLIENT_CA, "default client CA")?;
+    }
+    for path in ca_paths {
+        add_client_cas(&mut roots, &std::fs::read(&path)?, &path)?;
+    }
+
+    let cert_chain = certs(&mut open(&server.server_cert_path)?).map_err(|_| {
+        ErrorKind::Config.context(format!(
+            "failed to parse server certificate {}",
+            server.server_cert_path
+        ))
+    })?;
+    let key = load_private_key(&server.server_key_path)?;
+
+    let verifier = AllowAnyAuthenticatedClient::new(roots);
+    let revocations = match &server.revocation_dir {
+        Some(dir) => RevocationList::load(dir)?,
+        None => RevocationList::default(),
+    };
+    let verifier: Arc<dyn ClientCertVerifier> = match &server.revocation_dir {
+        Some(_) => Arc::new(RevocationCheckingVerifier {
+            inner: verifier,
+            revocations: revocations.clone(),
+        }),
+        None => verifier,
+    };
+
+    let mut config = ServerConfig::new(verifier);
+    config
+        .set_single_cert(cert_chain, key)
+        .map_err(|e| ErrorKind::Config.context(format!("invalid server certificate: {}", e)))?;
+    config.set_protocols(&[b"h2".to_vec()]);
+
+    Ok((TlsAcceptor::from(Arc::new(config)), revocations))
+}
+
+fn add_client_cas(roots: &mut RootCertStore, pem: &[u8], name: &str) -> Result<(), Error> {
+    match roots.add_pem_file(&mut BufReader::new(pem)) {
+        Ok((valid, _)) if valid > 0 => {
+            debug!("loaded {} client CA certificates from {}", valid, name);
+            Ok(())
+        }
+        _ => Err(ErrorKind::Config
+            .context(format!("no valid client CA certificates in {}", name))
+            .into()),
+    }
+}
+
+/// Loads a PKCS8 or RSA private key from a PEM file
+fn load_private_key(path: &str) -> Result<rustls::PrivateKey, Error> {
+    let pkcs8 = pkcs8_private_keys(&mut open(path)?).unwrap_or_default();
+    let rsa = rsa_private_keys(&mut open(path)?).unwrap_or_default();
+
+    match pkcs8.into_iter().chain(rsa).next() {
+        Some(key) => Ok(key),
+        None => Err(ErrorKind::Config
+            .context(format!("no private key found in {}", path))
+            .into()),
+    }
+}
+
+fn open(path: &str) -> Result<BufReader<File>, Error> {
+    Ok(BufReader::new(File::open(path)?))
+}
+
+fn get_modified_times(paths: &[String]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|p| Path::new(p).metadata().and_then(|m| m.modified()).ok())
+        .collect()
+}
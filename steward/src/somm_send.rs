@@ -1,73 +1,402 @@
+use crate::{
+    cork::fee_balance,
+    error::{Error, ErrorKind},
+};
+use abscissa_core::tracing::log::{debug, warn};
 use deep_space::coin::Coin;
-use deep_space::error::CosmosGrpcError;
+use deep_space::Address;
 use deep_space::Contact;
 use deep_space::Fee;
 use deep_space::Msg;
 use deep_space::PrivateKey as CosmosPrivateKey;
 use gravity_bridge::gravity_proto::cosmos_sdk_proto::cosmos::{
-    base::abci::v1beta1::TxResponse, tx::v1beta1::BroadcastMode,
+    base::abci::v1beta1::TxResponse,
+    tx::v1beta1::{
+        service_client::ServiceClient as TxServiceClient, BroadcastMode, BroadcastTxRequest,
+        SimulateRequest,
+    },
 };
+use lazy_static::lazy_static;
+use regex::Regex;
+use sha2::{Digest, Sha256};
 use somm_proto::cork::Cork;
 use somm_proto::cork::{MsgScheduleCorkRequest, MsgSubmitCorkRequest};
-use std::{result::Result, time::Duration};
+use std::{
+    collections::HashMap,
+    result::Result,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tonic::{Code, Status};
 
 pub const TIMEOUT: Duration = Duration::from_secs(60);
 pub const MEMO: &str = "Sent using Somm Orchestrator";
+/// Gas limit of the transaction signed for simulation. Simulation doesn't enforce it, but it must
+/// be high enough for the ante handler to accept the transaction.
+const SIMULATION_GAS_LIMIT: u64 = 10_000_000;
+/// Attempts made to broadcast a transaction before giving up
+const MAX_BROADCAST_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+/// Result code of the Cosmos SDK's `ErrWrongSequence`
+const WRONG_SEQUENCE_CODE: u32 = 32;
+/// Result code of the Cosmos SDK's `ErrTxInMempoolCache`
+const TX_IN_MEMPOOL_CACHE_CODE: u32 = 19;
+
+lazy_static! {
+    /// Broadcast state of each signer. Holding a signer's lock serializes its broadcasts so that
+    /// concurrent sends are assigned consecutive sequences.
+    static ref SIGNERS: Mutex<HashMap<String, Arc<tokio::sync::Mutex<SignerState>>>> =
+        Mutex::new(HashMap::new());
+    static ref EXPECTED_SEQUENCE: Regex = Regex::new(r"expected (\d+), got \d+").unwrap();
+}
+
+#[derive(Default)]
+struct SignerState {
+    /// Sequence to use for the next transaction, if known locally. Transactions accepted into the
+    /// mempool are not yet reflected in the account sequence reported by the node.
+    next_sequence: Option<u64>,
+}
 
 pub async fn send_cork(
-    contact: &Contact,
+    contacts: &[Contact],
     cork: Cork,
     delegate_address: String,
     delegate_key: &CosmosPrivateKey,
-    fee: Coin,
-) -> Result<TxResponse, CosmosGrpcError> {
-    let msg = MsgSubmitCorkRequest {
-        cork: Some(cork),
-        signer: delegate_address,
-    };
-    let msg = Msg::new("/cork.v1.MsgSubmitCorkRequest", msg);
-    __send_messages(contact, delegate_key, fee, vec![msg]).await
+    gas_price: (f64, String),
+    gas_adjustment: f64,
+) -> Result<TxResponse, Error> {
+    send_corks(
+        contacts,
+        vec![cork],
+        delegate_address,
+        delegate_key,
+        gas_price,
+        gas_adjustment,
+    )
+    .await
+}
+
+/// Submits each of `corks` in a single transaction
+pub async fn send_corks(
+    contacts: &[Contact],
+    corks: Vec<Cork>,
+    delegate_address: String,
+    delegate_key: &CosmosPrivateKey,
+    gas_price: (f64, String),
+    gas_adjustment: f64,
+) -> Result<TxResponse, Error> {
+    let msgs = corks
+        .into_iter()
+        .map(|cork| {
+            let msg = MsgSubmitCorkRequest {
+                cork: Some(cork),
+                signer: delegate_address.clone(),
+            };
+            Msg::new("/cork.v1.MsgSubmitCorkRequest", msg)
+        })
+        .collect();
+    __send_messages(contacts, delegate_key, gas_price, gas_adjustment, msgs).await
 }
 
 pub async fn schedule_cork(
-    contact: &Contact,
+    contacts: &[Contact],
     cork: Cork,
     delegate_address: String,
     delegate_key: &CosmosPrivateKey,
-    fee: Coin,
+    gas_price: (f64, String),
+    gas_adjustment: f64,
     block_height: u64,
-) -> Result<TxResponse, CosmosGrpcError> {
+) -> Result<TxResponse, Error> {
     let msg = MsgScheduleCorkRequest {
         cork: Some(cork),
         signer: delegate_address,
         block_height,
     };
     let msg = Msg::new("/cork.v1.MsgScheduleCorkRequest", msg);
-    __send_messages(contact, delegate_key, fee, vec![msg]).await
+    __send_messages(contacts, delegate_key, gas_price, gas_adjustment, vec![msg]).await
 }
 
+/// Signs and broadcasts `messages`, with a gas limit of the simulated gas used multiplied by
+/// `gas_adjustment` and a fee of the gas limit multiplied by the gas price, then waits for the
+/// transaction to be included in a block.
+///
+/// Broadcasts from the same signer are serialized and assigned sequences locally. A broadcast
+/// rejected for an account sequence mismatch is signed again with the sequence the node expects,
+/// and one that fails with a transport error or a transient gRPC status is retried against the
+/// next endpoint in `contacts` with exponential backoff. A transaction whose broadcast failed in
+/// transit may already be in a mempool, so the same signed bytes are sent again rather than a new
+/// transaction. Transactions rejected in simulation or by `CheckTx` are returned without retrying.
 async fn __send_messages(
-    contact: &Contact,
+    contacts: &[Contact],
     cosmos_key: &CosmosPrivateKey,
-    fee: Coin,
+    gas_price: (f64, String),
+    gas_adjustment: f64,
     messages: Vec<Msg>,
-) -> Result<TxResponse, CosmosGrpcError> {
-    let cosmos_address = cosmos_key.to_address(&contact.get_prefix())?;
+) -> Result<TxResponse, Error> {
+    if contacts.is_empty() {
+        return Err(ErrorKind::Config
+            .context("no cosmos grpc endpoints configured")
+            .into());
+    }
+
+    let cosmos_address = cosmos_key.to_address(&contacts[0].get_prefix())?;
+    let signer = get_signer(&cosmos_address.to_string());
+    let mut state = signer.lock().await;
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 0;
+    // Signed transaction and its sequence, if its last broadcast failed in transit
+    let mut in_transit: Option<(Vec<u8>, u64)> = None;
+    let (contact, response) = loop {
+        attempt += 1;
+        let contact = &contacts[(attempt as usize - 1) % contacts.len()];
+        let resending = in_transit.is_some();
+        let signed = match in_transit.take() {
+            Some(signed) => Ok(signed),
+            None => {
+                sign(
+                    contact,
+                    cosmos_key,
+                    cosmos_address,
+                    &gas_price,
+                    gas_adjustment,
+                    &messages,
+                    state.next_sequence,
+                )
+                .await
+            }
+        };
+        let err: Error = match signed {
+            Ok((tx_bytes, sequence)) => match send_tx(contact, &tx_bytes).await {
+                Ok(response) if response.code == 0 => {
+                    state.next_sequence = Some(sequence + 1);
+                    break (contact, response);
+                }
+                Ok(response) if resending && is_in_mempool_response(&response) => {
+                    state.next_sequence = Some(sequence + 1);
+                    break (contact, response_for(&tx_bytes));
+                }
+                Ok(response) if resending && is_sequence_mismatch_response(&response) => {
+                    // The sequence was used after the earlier broadcast, most likely by it
+                    warn!(
+                        "sequence {} was used since broadcasting transaction {}, waiting for it",
+                        sequence, response.txhash
+                    );
+                    state.next_sequence = Some(sequence + 1);
+                    break (contact, response_for(&tx_bytes));
+                }
+                Ok(response) if is_sequence_mismatch_response(&response) => {
+                    ErrorKind::GrpcError.context(response.raw_log).into()
+                }
+                Ok(response) => break (contact, response),
+                Err(err) if *err.kind() == ErrorKind::GrpcError => {
+                    in_transit = Some((tx_bytes, sequence));
+                    err
+                }
+                Err(err) => err,
+            },
+            Err(err) => err,
+        };
+
+        let message = err.to_string();
+        if attempt >= MAX_BROADCAST_ATTEMPTS {
+            if in_transit.is_some() {
+                return Err(ErrorKind::Unconfirmed
+                    .context(format!("transaction may have been broadcast: {}", message))
+                    .into());
+            }
+            return Err(err);
+        }
+        if message.contains("account sequence mismatch") {
+            state.next_sequence = EXPECTED_SEQUENCE
+                .captures(&message)
+                .and_then(|c| c[1].parse().ok());
+            debug!(
+                "account sequence mismatch, retrying with sequence {:?}",
+                state.next_sequence
+            );
+        } else if *err.kind() == ErrorKind::GrpcError {
+            warn!(
+                "broadcast attempt {} failed, retrying in {:?}: {}",
+                attempt, backoff, message
+            );
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        } else {
+            return Err(err);
+        }
+    };
+
+    // The next transaction can be broadcast as soon as this one is in the mempool
+    drop(state);
+
+    // Rejected by the node, so it will never be included in a block
+    if response.code != 0 {
+        return Ok(response);
+    }
+
+    contact
+        .wait_for_tx(response, TIMEOUT)
+        .await
+        .map_err(|err| ErrorKind::Unconfirmed.context(err).into())
+}
+
+/// Checks the fee balance, then simulates and signs `messages`, returning the signed transaction
+/// and the sequence it was signed with
+async fn sign(
+    contact: &Contact,
+    cosmos_key: &CosmosPrivateKey,
+    cosmos_address: Address,
+    gas_price: &(f64, String),
+    gas_adjustment: f64,
+    messages: &[Msg],
+    next_sequence: Option<u64>,
+) -> Result<(Vec<u8>, u64), Error> {
+    debug!("checking delegate fee balance");
+    fee_balance::check(contact).await?;
+
+    let gas_used =
+        simulate_gas(contact, cosmos_key, cosmos_address, messages, next_sequence).await?;
+    let gas_limit = (gas_used as f64 * gas_adjustment).ceil() as u64;
+    let fee_amount = (gas_limit as f64 * gas_price.0).ceil() as u64;
+    debug!(
+        "simulated gas used {}, sending with gas limit {} and fee {}{}",
+        gas_used, gas_limit, fee_amount, gas_price.1
+    );
+    let fee = Fee {
+        amount: vec![Coin {
+            amount: fee_amount.into(),
+            denom: gas_price.1.clone(),
+        }],
+        gas_limit,
+        granter: None,
+        payer: None,
+    };
+
+    let mut args = contact.get_message_args(cosmos_address, fee).await?;
+    args.sequence = args.sequence.max(next_sequence.unwrap_or_default());
+    let sequence = args.sequence;
+
+    let tx_bytes = cosmos_key.sign_std_msg(messages, args, MEMO)?;
+
+    Ok((tx_bytes, sequence))
+}
+
+/// Broadcasts a signed transaction once, returning the sync broadcast response. An error means the
+/// node may or may not have received the transaction.
+async fn send_tx(contact: &Contact, tx_bytes: &[u8]) -> Result<TxResponse, Error> {
+    let mut client = TxServiceClient::connect(contact.get_url()).await?;
+    let response = client
+        .broadcast_tx(BroadcastTxRequest {
+            tx_bytes: tx_bytes.to_vec(),
+            mode: BroadcastMode::Sync.into(),
+        })
+        .await
+        .map_err(status_error)?
+        .into_inner();
+
+    Ok(response
+        .tx_response
+        .ok_or_else(|| ErrorKind::GrpcError.context("broadcast response has no tx response"))?)
+}
 
+/// Returns the gas used by `messages` when simulated by the node
+async fn simulate_gas(
+    contact: &Contact,
+    cosmos_key: &CosmosPrivateKey,
+    cosmos_address: Address,
+    messages: &[Msg],
+    next_sequence: Option<u64>,
+) -> Result<u64, Error> {
     let fee = Fee {
-        amount: vec![fee],
-        gas_limit: 500_000u64 * (messages.len() as u64),
+        amount: Vec::new(),
+        gas_limit: SIMULATION_GAS_LIMIT,
         granter: None,
         payer: None,
     };
+    let mut args = contact.get_message_args(cosmos_address, fee).await?;
+    args.sequence = args.sequence.max(next_sequence.unwrap_or_default());
+    let tx_bytes = cosmos_key.sign_std_msg(messages, args, MEMO)?;
+
+    let mut client = TxServiceClient::connect(contact.get_url()).await?;
+    let response = client
+        .simulate(SimulateRequest { tx: None, tx_bytes })
+        .await
+        .map_err(status_error)?
+        .into_inner();
+
+    match response.gas_info {
+        Some(info) => Ok(info.gas_used),
+        None => Err(ErrorKind::GrpcError
+            .context("simulation response is missing gas info")
+            .into()),
+    }
+}
+
+/// Converts a status returned by the node into a `GrpcError` to retry if it is transient, or
+/// otherwise a `Rejected` error, which is returned immediately
+fn status_error(status: Status) -> Error {
+    match status.code() {
+        Code::Unavailable | Code::DeadlineExceeded => ErrorKind::GrpcError.context(status).into(),
+        _ => ErrorKind::Rejected.context(status).into(),
+    }
+}
+
+fn get_signer(address: &str) -> Arc<tokio::sync::Mutex<SignerState>> {
+    SIGNERS
+        .lock()
+        .unwrap()
+        .entry(address.to_string())
+        .or_default()
+        .clone()
+}
+
+fn is_sequence_mismatch_response(response: &TxResponse) -> bool {
+    (response.codespace == "sdk" && response.code == WRONG_SEQUENCE_CODE)
+        || response.raw_log.contains("account sequence mismatch")
+}
+
+fn is_in_mempool_response(response: &TxResponse) -> bool {
+    response.codespace == "sdk" && response.code == TX_IN_MEMPOOL_CACHE_CODE
+}
+
+/// Returns a response identifying a broadcast transaction by its hash, to wait for it with
+fn response_for(tx_bytes: &[u8]) -> TxResponse {
+    TxResponse {
+        txhash: hex::encode_upper(Sha256::digest(tx_bytes)),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let args = contact.get_message_args(cosmos_address, fee).await?;
+    #[test]
+    fn expected_sequence_is_parsed_from_mismatch_log() {
+        let log = "account sequence mismatch, expected 42, got 41: incorrect account sequence";
+        let captures = EXPECTED_SEQUENCE.captures(log).unwrap();
 
-    let msg_bytes = cosmos_key.sign_std_msg(&messages, args, MEMO)?;
+        assert_eq!(&captures[1], "42");
+    }
 
-    let response = contact
-        .send_transaction(msg_bytes, BroadcastMode::Sync)
-        .await?;
+    #[test]
+    fn only_transient_statuses_are_retried() {
+        assert_eq!(
+            *status_error(Status::unavailable("connection refused")).kind(),
+            ErrorKind::GrpcError
+        );
+        assert_eq!(
+            *status_error(Status::deadline_exceeded("timeout")).kind(),
+            ErrorKind::GrpcError
+        );
 
-    contact.wait_for_tx(response, TIMEOUT).await
+        let log = "failed to execute message; message index: 1: cellar not found: unauthorized";
+        let err = status_error(Status::unknown(log));
+        assert_eq!(*err.kind(), ErrorKind::Rejected);
+        assert!(std::error::Error::source(&err)
+            .unwrap()
+            .to_string()
+            .contains("message index: 1"));
+    }
 }
@@ -37,6 +37,15 @@ pub fn string_to_u256(value: String) -> Result<U256, Error> {
     }
 }
 
+pub fn string_to_address(value: String) -> Result<EthAddress, Error> {
+    match value.parse::<EthAddress>() {
+        Ok(v) => Ok(v),
+        Err(_) => Err(ErrorKind::SPCallError
+            .context(format!("failed to parse address {}", value))
+            .into()),
+    }
+}
+
 pub async fn get_chain(eth_client: Provider<Http>) -> Result<Chain, Error> {
     let chain_id_result = eth_client.get_chainid().await?;
     let chain_id = downcast_to_u64(chain_id_result);
@@ -0,0 +1,1273 @@
+pub use erc4626vault_mod::*;
+#[allow(clippy::too_many_arguments)]
+mod erc4626vault_mod {
+    #![allow(clippy::enum_variant_names)]
+    #![allow(dead_code)]
+    #![allow(clippy::type_complexity)]
+    #![allow(unused_imports)]
+    use ethers::contract::{
+        builders::{ContractCall, Event},
+        Contract, Lazy,
+    };
+    use ethers::core::{
+        abi::{Abi, Detokenize, InvalidOutputType, Token, Tokenizable},
+        types::*,
+    };
+    use ethers::providers::Middleware;
+    #[doc = "Erc4626Vault was auto-generated with ethers-rs Abigen. More information at: https://github.com/gakonst/ethers-rs"]
+    use std::sync::Arc;
+    pub static ERC4626VAULT_ABI: ethers::contract::Lazy<ethers::core::abi::Abi> =
+        ethers::contract::Lazy::new(|| {
+            serde_json :: from_str ("{\n  \"_format\": \"hh-sol-artifact-1\",\n  \"contractName\": \"ERC4626\",\n  \"sourceName\": \"src/base/ERC4626.sol\",\n  \"abi\": [\n    {\n      \"anonymous\": false,\n      \"inputs\": [\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"spender\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": false,\n          \"internalType\": \"uint256\",\n          \"name\": \"amount\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"Approval\",\n      \"type\": \"event\"\n    },\n    {\n      \"anonymous\": false,\n      \"inputs\": [\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"caller\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": false,\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"indexed\": false,\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"Deposit\",\n      \"type\": \"event\"\n    },\n    {\n      \"anonymous\": false,\n      \"inputs\": [\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"from\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"to\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": false,\n          \"internalType\": \"uint256\",\n          \"name\": \"amount\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"Transfer\",\n      \"type\": \"event\"\n    },\n    {\n      \"anonymous\": false,\n      \"inputs\": [\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"caller\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"receiver\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": true,\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        },\n        {\n          \"indexed\": false,\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"indexed\": false,\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"Withdraw\",\n      \"type\": \"event\"\n    },\n    {\n      \"inputs\": [],\n      \"name\": \"DOMAIN_SEPARATOR\",\n      \"outputs\": [\n        {\n          \"internalType\": \"bytes32\",\n          \"name\": \"\",\n          \"type\": \"bytes32\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"allowance\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"spender\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"amount\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"approve\",\n      \"outputs\": [\n        {\n          \"internalType\": \"bool\",\n          \"name\": \"\",\n          \"type\": \"bool\"\n        }\n      ],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [],\n      \"name\": \"asset\",\n      \"outputs\": [\n        {\n          \"internalType\": \"contract ERC20\",\n          \"name\": \"\",\n          \"type\": \"address\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"balanceOf\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"convertToAssets\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"convertToShares\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [],\n      \"name\": \"decimals\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint8\",\n          \"name\": \"\",\n          \"type\": \"uint8\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"receiver\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"deposit\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"receiver\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"maxDeposit\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"receiver\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"maxMint\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"maxRedeem\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"maxWithdraw\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"receiver\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"mint\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [],\n      \"name\": \"name\",\n      \"outputs\": [\n        {\n          \"internalType\": \"string\",\n          \"name\": \"\",\n          \"type\": \"string\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"nonces\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"spender\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"value\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"deadline\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"internalType\": \"uint8\",\n          \"name\": \"v\",\n          \"type\": \"uint8\"\n        },\n        {\n          \"internalType\": \"bytes32\",\n          \"name\": \"r\",\n          \"type\": \"bytes32\"\n        },\n        {\n          \"internalType\": \"bytes32\",\n          \"name\": \"s\",\n          \"type\": \"bytes32\"\n        }\n      ],\n      \"name\": \"permit\",\n      \"outputs\": [],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"previewDeposit\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"previewMint\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"previewRedeem\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"previewWithdraw\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"receiver\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"redeem\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [],\n      \"name\": \"symbol\",\n      \"outputs\": [\n        {\n          \"internalType\": \"string\",\n          \"name\": \"\",\n          \"type\": \"string\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [],\n      \"name\": \"totalAssets\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [],\n      \"name\": \"totalSupply\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"view\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"to\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"amount\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"transfer\",\n      \"outputs\": [\n        {\n          \"internalType\": \"bool\",\n          \"name\": \"\",\n          \"type\": \"bool\"\n        }\n      ],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"address\",\n          \"name\": \"from\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"to\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"amount\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"name\": \"transferFrom\",\n      \"outputs\": [\n        {\n          \"internalType\": \"bool\",\n          \"name\": \"\",\n          \"type\": \"bool\"\n        }\n      ],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    },\n    {\n      \"inputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"assets\",\n          \"type\": \"uint256\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"receiver\",\n          \"type\": \"address\"\n        },\n        {\n          \"internalType\": \"address\",\n          \"name\": \"owner\",\n          \"type\": \"address\"\n        }\n      ],\n      \"name\": \"withdraw\",\n      \"outputs\": [\n        {\n          \"internalType\": \"uint256\",\n          \"name\": \"shares\",\n          \"type\": \"uint256\"\n        }\n      ],\n      \"stateMutability\": \"nonpayable\",\n      \"type\": \"function\"\n    }\n  ],\n  \"bytecode\": \"0x\",\n  \"deployedBytecode\": \"0x\",\n  \"linkReferences\": {},\n  \"deployedLinkReferences\": {}\n}\n") . expect ("invalid abi")
+        });
+    #[derive(Clone)]
+    pub struct Erc4626Vault<M>(ethers::contract::Contract<M>);
+    impl<M> std::ops::Deref for Erc4626Vault<M> {
+        type Target = ethers::contract::Contract<M>;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+    impl<M: ethers::providers::Middleware> std::fmt::Debug for Erc4626Vault<M> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_tuple(stringify!(Erc4626Vault))
+                .field(&self.address())
+                .finish()
+        }
+    }
+    impl<'a, M: ethers::providers::Middleware> Erc4626Vault<M> {
+        #[doc = r" Creates a new contract instance with the specified `ethers`"]
+        #[doc = r" client at the given `Address`. The contract derefs to a `ethers::Contract`"]
+        #[doc = r" object"]
+        pub fn new<T: Into<ethers::core::types::Address>>(
+            address: T,
+            client: ::std::sync::Arc<M>,
+        ) -> Self {
+            let contract =
+                ethers::contract::Contract::new(address.into(), ERC4626VAULT_ABI.clone(), client);
+            Self(contract)
+        }
+        #[doc = "Calls the contract's `DOMAIN_SEPARATOR` (0x3644e515) function"]
+        pub fn domain_separator(&self) -> ethers::contract::builders::ContractCall<M, [u8; 32]> {
+            self.0
+                .method_hash([54, 68, 229, 21], ())
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `allowance` (0xdd62ed3e) function"]
+        pub fn allowance(
+            &self,
+            p0: ethers::core::types::Address,
+            p1: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([221, 98, 237, 62], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `approve` (0x095ea7b3) function"]
+        pub fn approve(
+            &self,
+            spender: ethers::core::types::Address,
+            amount: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([9, 94, 167, 179], (spender, amount))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `asset` (0x38d52e0f) function"]
+        pub fn asset(
+            &self,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::Address> {
+            self.0
+                .method_hash([56, 213, 46, 15], ())
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `balanceOf` (0x70a08231) function"]
+        pub fn balance_of(
+            &self,
+            p0: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([112, 160, 130, 49], p0)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `convertToAssets` (0x07a2d13a) function"]
+        pub fn convert_to_assets(
+            &self,
+            shares: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([7, 162, 209, 58], shares)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `convertToShares` (0xc6e6f592) function"]
+        pub fn convert_to_shares(
+            &self,
+            assets: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([198, 230, 245, 146], assets)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `decimals` (0x313ce567) function"]
+        pub fn decimals(&self) -> ethers::contract::builders::ContractCall<M, u8> {
+            self.0
+                .method_hash([49, 60, 229, 103], ())
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `deposit` (0x6e553f65) function"]
+        pub fn deposit(
+            &self,
+            assets: ethers::core::types::U256,
+            receiver: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([110, 85, 63, 101], (assets, receiver))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `maxDeposit` (0x402d267d) function"]
+        pub fn max_deposit(
+            &self,
+            receiver: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([64, 45, 38, 125], receiver)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `maxMint` (0xc63d75b6) function"]
+        pub fn max_mint(
+            &self,
+            receiver: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([198, 61, 117, 182], receiver)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `maxRedeem` (0xd905777e) function"]
+        pub fn max_redeem(
+            &self,
+            owner: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([217, 5, 119, 126], owner)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `maxWithdraw` (0xce96cb77) function"]
+        pub fn max_withdraw(
+            &self,
+            owner: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([206, 150, 203, 119], owner)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `mint` (0x94bf804d) function"]
+        pub fn mint(
+            &self,
+            shares: ethers::core::types::U256,
+            receiver: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([148, 191, 128, 77], (shares, receiver))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `name` (0x06fdde03) function"]
+        pub fn name(&self) -> ethers::contract::builders::ContractCall<M, String> {
+            self.0
+                .method_hash([6, 253, 222, 3], ())
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `nonces` (0x7ecebe00) function"]
+        pub fn nonces(
+            &self,
+            p0: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([126, 206, 190, 0], p0)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `permit` (0xd505accf) function"]
+        pub fn permit(
+            &self,
+            owner: ethers::core::types::Address,
+            spender: ethers::core::types::Address,
+            value: ethers::core::types::U256,
+            deadline: ethers::core::types::U256,
+            v: u8,
+            r: [u8; 32],
+            s: [u8; 32],
+        ) -> ethers::contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash(
+                    [213, 5, 172, 207],
+                    (owner, spender, value, deadline, v, r, s),
+                )
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `previewDeposit` (0xef8b30f7) function"]
+        pub fn preview_deposit(
+            &self,
+            assets: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([239, 139, 48, 247], assets)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `previewMint` (0xb3d7f6b9) function"]
+        pub fn preview_mint(
+            &self,
+            shares: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([179, 215, 246, 185], shares)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `previewRedeem` (0x4cdad506) function"]
+        pub fn preview_redeem(
+            &self,
+            shares: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([76, 218, 213, 6], shares)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `previewWithdraw` (0x0a28a477) function"]
+        pub fn preview_withdraw(
+            &self,
+            assets: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([10, 40, 164, 119], assets)
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `redeem` (0xba087652) function"]
+        pub fn redeem(
+            &self,
+            shares: ethers::core::types::U256,
+            receiver: ethers::core::types::Address,
+            owner: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([186, 8, 118, 82], (shares, receiver, owner))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `symbol` (0x95d89b41) function"]
+        pub fn symbol(&self) -> ethers::contract::builders::ContractCall<M, String> {
+            self.0
+                .method_hash([149, 216, 155, 65], ())
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `totalAssets` (0x01e1d114) function"]
+        pub fn total_assets(
+            &self,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([1, 225, 209, 20], ())
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `totalSupply` (0x18160ddd) function"]
+        pub fn total_supply(
+            &self,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([24, 22, 13, 221], ())
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `transfer` (0xa9059cbb) function"]
+        pub fn transfer(
+            &self,
+            to: ethers::core::types::Address,
+            amount: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([169, 5, 156, 187], (to, amount))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `transferFrom` (0x23b872dd) function"]
+        pub fn transfer_from(
+            &self,
+            from: ethers::core::types::Address,
+            to: ethers::core::types::Address,
+            amount: ethers::core::types::U256,
+        ) -> ethers::contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([35, 184, 114, 221], (from, to, amount))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Calls the contract's `withdraw` (0xb460af94) function"]
+        pub fn withdraw(
+            &self,
+            assets: ethers::core::types::U256,
+            receiver: ethers::core::types::Address,
+            owner: ethers::core::types::Address,
+        ) -> ethers::contract::builders::ContractCall<M, ethers::core::types::U256> {
+            self.0
+                .method_hash([180, 96, 175, 148], (assets, receiver, owner))
+                .expect("method not found (this should never happen)")
+        }
+        #[doc = "Gets the contract's `Approval` event"]
+        pub fn approval_filter(&self) -> ethers::contract::builders::Event<M, ApprovalFilter> {
+            self.0.event()
+        }
+        #[doc = "Gets the contract's `Deposit` event"]
+        pub fn deposit_filter(&self) -> ethers::contract::builders::Event<M, DepositFilter> {
+            self.0.event()
+        }
+        #[doc = "Gets the contract's `Transfer` event"]
+        pub fn transfer_filter(&self) -> ethers::contract::builders::Event<M, TransferFilter> {
+            self.0.event()
+        }
+        #[doc = "Gets the contract's `Withdraw` event"]
+        pub fn withdraw_filter(&self) -> ethers::contract::builders::Event<M, WithdrawFilter> {
+            self.0.event()
+        }
+        #[doc = r" Returns an [`Event`](#ethers_contract::builders::Event) builder for all events of this contract"]
+        pub fn events(&self) -> ethers::contract::builders::Event<M, Erc4626VaultEvents> {
+            self.0.event_with_filter(Default::default())
+        }
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthEvent,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethevent(name = "Approval", abi = "Approval(address,address,uint256)")]
+    pub struct ApprovalFilter {
+        #[ethevent(indexed)]
+        pub owner: ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub spender: ethers::core::types::Address,
+        pub amount: ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthEvent,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethevent(name = "Deposit", abi = "Deposit(address,address,uint256,uint256)")]
+    pub struct DepositFilter {
+        #[ethevent(indexed)]
+        pub caller: ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub owner: ethers::core::types::Address,
+        pub assets: ethers::core::types::U256,
+        pub shares: ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthEvent,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethevent(name = "Transfer", abi = "Transfer(address,address,uint256)")]
+    pub struct TransferFilter {
+        #[ethevent(indexed)]
+        pub from: ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub to: ethers::core::types::Address,
+        pub amount: ethers::core::types::U256,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthEvent,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethevent(
+        name = "Withdraw",
+        abi = "Withdraw(address,address,address,uint256,uint256)"
+    )]
+    pub struct WithdrawFilter {
+        #[ethevent(indexed)]
+        pub caller: ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub receiver: ethers::core::types::Address,
+        #[ethevent(indexed)]
+        pub owner: ethers::core::types::Address,
+        pub assets: ethers::core::types::U256,
+        pub shares: ethers::core::types::U256,
+    }
+    #[derive(Debug, Clone, PartialEq, Eq, ethers :: contract :: EthAbiType)]
+    pub enum Erc4626VaultEvents {
+        ApprovalFilter(ApprovalFilter),
+        DepositFilter(DepositFilter),
+        TransferFilter(TransferFilter),
+        WithdrawFilter(WithdrawFilter),
+    }
+    impl ethers::contract::EthLogDecode for Erc4626VaultEvents {
+        fn decode_log(log: &ethers::core::abi::RawLog) -> Result<Self, ethers::core::abi::Error>
+        where
+            Self: Sized,
+        {
+            if let Ok(decoded) = ApprovalFilter::decode_log(log) {
+                return Ok(Erc4626VaultEvents::ApprovalFilter(decoded));
+            }
+            if let Ok(decoded) = DepositFilter::decode_log(log) {
+                return Ok(Erc4626VaultEvents::DepositFilter(decoded));
+            }
+            if let Ok(decoded) = TransferFilter::decode_log(log) {
+                return Ok(Erc4626VaultEvents::TransferFilter(decoded));
+            }
+            if let Ok(decoded) = WithdrawFilter::decode_log(log) {
+                return Ok(Erc4626VaultEvents::WithdrawFilter(decoded));
+            }
+            Err(ethers::core::abi::Error::InvalidData)
+        }
+    }
+    impl ::std::fmt::Display for Erc4626VaultEvents {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match self {
+                Erc4626VaultEvents::ApprovalFilter(element) => element.fmt(f),
+                Erc4626VaultEvents::DepositFilter(element) => element.fmt(f),
+                Erc4626VaultEvents::TransferFilter(element) => element.fmt(f),
+                Erc4626VaultEvents::WithdrawFilter(element) => element.fmt(f),
+            }
+        }
+    }
+    #[doc = "Container type for all input parameters for the `DOMAIN_SEPARATOR`function with signature `DOMAIN_SEPARATOR()` and selector `[54, 68, 229, 21]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "DOMAIN_SEPARATOR", abi = "DOMAIN_SEPARATOR()")]
+    pub struct DomainSeparatorCall;
+    #[doc = "Container type for all input parameters for the `allowance`function with signature `allowance(address,address)` and selector `[221, 98, 237, 62]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "allowance", abi = "allowance(address,address)")]
+    pub struct AllowanceCall(
+        pub ethers::core::types::Address,
+        pub ethers::core::types::Address,
+    );
+    #[doc = "Container type for all input parameters for the `approve`function with signature `approve(address,uint256)` and selector `[9, 94, 167, 179]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "approve", abi = "approve(address,uint256)")]
+    pub struct ApproveCall {
+        pub spender: ethers::core::types::Address,
+        pub amount: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `asset`function with signature `asset()` and selector `[56, 213, 46, 15]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "asset", abi = "asset()")]
+    pub struct AssetCall;
+    #[doc = "Container type for all input parameters for the `balanceOf`function with signature `balanceOf(address)` and selector `[112, 160, 130, 49]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "balanceOf", abi = "balanceOf(address)")]
+    pub struct BalanceOfCall(pub ethers::core::types::Address);
+    #[doc = "Container type for all input parameters for the `convertToAssets`function with signature `convertToAssets(uint256)` and selector `[7, 162, 209, 58]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "convertToAssets", abi = "convertToAssets(uint256)")]
+    pub struct ConvertToAssetsCall {
+        pub shares: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `convertToShares`function with signature `convertToShares(uint256)` and selector `[198, 230, 245, 146]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "convertToShares", abi = "convertToShares(uint256)")]
+    pub struct ConvertToSharesCall {
+        pub assets: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `decimals`function with signature `decimals()` and selector `[49, 60, 229, 103]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "decimals", abi = "decimals()")]
+    pub struct DecimalsCall;
+    #[doc = "Container type for all input parameters for the `deposit`function with signature `deposit(uint256,address)` and selector `[110, 85, 63, 101]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "deposit", abi = "deposit(uint256,address)")]
+    pub struct DepositCall {
+        pub assets: ethers::core::types::U256,
+        pub receiver: ethers::core::types::Address,
+    }
+    #[doc = "Container type for all input parameters for the `maxDeposit`function with signature `maxDeposit(address)` and selector `[64, 45, 38, 125]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "maxDeposit", abi = "maxDeposit(address)")]
+    pub struct MaxDepositCall {
+        pub receiver: ethers::core::types::Address,
+    }
+    #[doc = "Container type for all input parameters for the `maxMint`function with signature `maxMint(address)` and selector `[198, 61, 117, 182]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "maxMint", abi = "maxMint(address)")]
+    pub struct MaxMintCall {
+        pub receiver: ethers::core::types::Address,
+    }
+    #[doc = "Container type for all input parameters for the `maxRedeem`function with signature `maxRedeem(address)` and selector `[217, 5, 119, 126]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "maxRedeem", abi = "maxRedeem(address)")]
+    pub struct MaxRedeemCall {
+        pub owner: ethers::core::types::Address,
+    }
+    #[doc = "Container type for all input parameters for the `maxWithdraw`function with signature `maxWithdraw(address)` and selector `[206, 150, 203, 119]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "maxWithdraw", abi = "maxWithdraw(address)")]
+    pub struct MaxWithdrawCall {
+        pub owner: ethers::core::types::Address,
+    }
+    #[doc = "Container type for all input parameters for the `mint`function with signature `mint(uint256,address)` and selector `[148, 191, 128, 77]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "mint", abi = "mint(uint256,address)")]
+    pub struct MintCall {
+        pub shares: ethers::core::types::U256,
+        pub receiver: ethers::core::types::Address,
+    }
+    #[doc = "Container type for all input parameters for the `name`function with signature `name()` and selector `[6, 253, 222, 3]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "name", abi = "name()")]
+    pub struct NameCall;
+    #[doc = "Container type for all input parameters for the `nonces`function with signature `nonces(address)` and selector `[126, 206, 190, 0]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "nonces", abi = "nonces(address)")]
+    pub struct NoncesCall(pub ethers::core::types::Address);
+    #[doc = "Container type for all input parameters for the `permit`function with signature `permit(address,address,uint256,uint256,uint8,bytes32,bytes32)` and selector `[213, 5, 172, 207]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(
+        name = "permit",
+        abi = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)"
+    )]
+    pub struct PermitCall {
+        pub owner: ethers::core::types::Address,
+        pub spender: ethers::core::types::Address,
+        pub value: ethers::core::types::U256,
+        pub deadline: ethers::core::types::U256,
+        pub v: u8,
+        pub r: [u8; 32],
+        pub s: [u8; 32],
+    }
+    #[doc = "Container type for all input parameters for the `previewDeposit`function with signature `previewDeposit(uint256)` and selector `[239, 139, 48, 247]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "previewDeposit", abi = "previewDeposit(uint256)")]
+    pub struct PreviewDepositCall {
+        pub assets: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `previewMint`function with signature `previewMint(uint256)` and selector `[179, 215, 246, 185]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "previewMint", abi = "previewMint(uint256)")]
+    pub struct PreviewMintCall {
+        pub shares: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `previewRedeem`function with signature `previewRedeem(uint256)` and selector `[76, 218, 213, 6]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "previewRedeem", abi = "previewRedeem(uint256)")]
+    pub struct PreviewRedeemCall {
+        pub shares: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `previewWithdraw`function with signature `previewWithdraw(uint256)` and selector `[10, 40, 164, 119]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "previewWithdraw", abi = "previewWithdraw(uint256)")]
+    pub struct PreviewWithdrawCall {
+        pub assets: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `redeem`function with signature `redeem(uint256,address,address)` and selector `[186, 8, 118, 82]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "redeem", abi = "redeem(uint256,address,address)")]
+    pub struct RedeemCall {
+        pub shares: ethers::core::types::U256,
+        pub receiver: ethers::core::types::Address,
+        pub owner: ethers::core::types::Address,
+    }
+    #[doc = "Container type for all input parameters for the `symbol`function with signature `symbol()` and selector `[149, 216, 155, 65]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "symbol", abi = "symbol()")]
+    pub struct SymbolCall;
+    #[doc = "Container type for all input parameters for the `totalAssets`function with signature `totalAssets()` and selector `[1, 225, 209, 20]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "totalAssets", abi = "totalAssets()")]
+    pub struct TotalAssetsCall;
+    #[doc = "Container type for all input parameters for the `totalSupply`function with signature `totalSupply()` and selector `[24, 22, 13, 221]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "totalSupply", abi = "totalSupply()")]
+    pub struct TotalSupplyCall;
+    #[doc = "Container type for all input parameters for the `transfer`function with signature `transfer(address,uint256)` and selector `[169, 5, 156, 187]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "transfer", abi = "transfer(address,uint256)")]
+    pub struct TransferCall {
+        pub to: ethers::core::types::Address,
+        pub amount: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `transferFrom`function with signature `transferFrom(address,address,uint256)` and selector `[35, 184, 114, 221]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "transferFrom", abi = "transferFrom(address,address,uint256)")]
+    pub struct TransferFromCall {
+        pub from: ethers::core::types::Address,
+        pub to: ethers::core::types::Address,
+        pub amount: ethers::core::types::U256,
+    }
+    #[doc = "Container type for all input parameters for the `withdraw`function with signature `withdraw(uint256,address,address)` and selector `[180, 96, 175, 148]`"]
+    #[derive(
+        Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        ethers :: contract :: EthCall,
+        ethers :: contract :: EthDisplay,
+        serde :: Deserialize,
+        serde :: Serialize,
+    )]
+    #[ethcall(name = "withdraw", abi = "withdraw(uint256,address,address)")]
+    pub struct WithdrawCall {
+        pub assets: ethers::core::types::U256,
+        pub receiver: ethers::core::types::Address,
+        pub owner: ethers::core::types::Address,
+    }
+    #[derive(Debug, Clone, PartialEq, Eq, ethers :: contract :: EthAbiType)]
+    pub enum Erc4626VaultCalls {
+        DomainSeparator(DomainSeparatorCall),
+        Allowance(AllowanceCall),
+        Approve(ApproveCall),
+        Asset(AssetCall),
+        BalanceOf(BalanceOfCall),
+        ConvertToAssets(ConvertToAssetsCall),
+        ConvertToShares(ConvertToSharesCall),
+        Decimals(DecimalsCall),
+        Deposit(DepositCall),
+        MaxDeposit(MaxDepositCall),
+        MaxMint(MaxMintCall),
+        MaxRedeem(MaxRedeemCall),
+        MaxWithdraw(MaxWithdrawCall),
+        Mint(MintCall),
+        Name(NameCall),
+        Nonces(NoncesCall),
+        Permit(PermitCall),
+        PreviewDeposit(PreviewDepositCall),
+        PreviewMint(PreviewMintCall),
+        PreviewRedeem(PreviewRedeemCall),
+        PreviewWithdraw(PreviewWithdrawCall),
+        Redeem(RedeemCall),
+        Symbol(SymbolCall),
+        TotalAssets(TotalAssetsCall),
+        TotalSupply(TotalSupplyCall),
+        Transfer(TransferCall),
+        TransferFrom(TransferFromCall),
+        Withdraw(WithdrawCall),
+    }
+    impl ethers::core::abi::AbiDecode for Erc4626VaultCalls {
+        fn decode(data: impl AsRef<[u8]>) -> Result<Self, ethers::core::abi::AbiError> {
+            if let Ok(decoded) =
+                <DomainSeparatorCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::DomainSeparator(decoded));
+            }
+            if let Ok(decoded) =
+                <AllowanceCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Allowance(decoded));
+            }
+            if let Ok(decoded) =
+                <ApproveCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Approve(decoded));
+            }
+            if let Ok(decoded) = <AssetCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Asset(decoded));
+            }
+            if let Ok(decoded) =
+                <BalanceOfCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::BalanceOf(decoded));
+            }
+            if let Ok(decoded) =
+                <ConvertToAssetsCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::ConvertToAssets(decoded));
+            }
+            if let Ok(decoded) =
+                <ConvertToSharesCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::ConvertToShares(decoded));
+            }
+            if let Ok(decoded) =
+                <DecimalsCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Decimals(decoded));
+            }
+            if let Ok(decoded) =
+                <DepositCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Deposit(decoded));
+            }
+            if let Ok(decoded) =
+                <MaxDepositCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::MaxDeposit(decoded));
+            }
+            if let Ok(decoded) =
+                <MaxMintCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::MaxMint(decoded));
+            }
+            if let Ok(decoded) =
+                <MaxRedeemCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::MaxRedeem(decoded));
+            }
+            if let Ok(decoded) =
+                <MaxWithdrawCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::MaxWithdraw(decoded));
+            }
+            if let Ok(decoded) = <MintCall as ethers::core::abi::AbiDecode>::decode(data.as_ref()) {
+                return Ok(Erc4626VaultCalls::Mint(decoded));
+            }
+            if let Ok(decoded) = <NameCall as ethers::core::abi::AbiDecode>::decode(data.as_ref()) {
+                return Ok(Erc4626VaultCalls::Name(decoded));
+            }
+            if let Ok(decoded) = <NoncesCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Nonces(decoded));
+            }
+            if let Ok(decoded) = <PermitCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Permit(decoded));
+            }
+            if let Ok(decoded) =
+                <PreviewDepositCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::PreviewDeposit(decoded));
+            }
+            if let Ok(decoded) =
+                <PreviewMintCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::PreviewMint(decoded));
+            }
+            if let Ok(decoded) =
+                <PreviewRedeemCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::PreviewRedeem(decoded));
+            }
+            if let Ok(decoded) =
+                <PreviewWithdrawCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::PreviewWithdraw(decoded));
+            }
+            if let Ok(decoded) = <RedeemCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Redeem(decoded));
+            }
+            if let Ok(decoded) = <SymbolCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Symbol(decoded));
+            }
+            if let Ok(decoded) =
+                <TotalAssetsCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::TotalAssets(decoded));
+            }
+            if let Ok(decoded) =
+                <TotalSupplyCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::TotalSupply(decoded));
+            }
+            if let Ok(decoded) =
+                <TransferCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Transfer(decoded));
+            }
+            if let Ok(decoded) =
+                <TransferFromCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::TransferFrom(decoded));
+            }
+            if let Ok(decoded) =
+                <WithdrawCall as ethers::core::abi::AbiDecode>::decode(data.as_ref())
+            {
+                return Ok(Erc4626VaultCalls::Withdraw(decoded));
+            }
+            Err(ethers::core::abi::Error::InvalidData.into())
+        }
+    }
+    impl ethers::core::abi::AbiEncode for Erc4626VaultCalls {
+        fn encode(self) -> Vec<u8> {
+            match self {
+                Erc4626VaultCalls::DomainSeparator(element) => element.encode(),
+                Erc4626VaultCalls::Allowance(element) => element.encode(),
+                Erc4626VaultCalls::Approve(element) => element.encode(),
+                Erc4626VaultCalls::Asset(element) => element.encode(),
+                Erc4626VaultCalls::BalanceOf(element) => element.encode(),
+                Erc4626VaultCalls::ConvertToAssets(element) => element.encode(),
+                Erc4626VaultCalls::ConvertToShares(element) => element.encode(),
+                Erc4626VaultCalls::Decimals(element) => element.encode(),
+                Erc4626VaultCalls::Deposit(element) => element.encode(),
+                Erc4626VaultCalls::MaxDeposit(element) => element.encode(),
+                Erc4626VaultCalls::MaxMint(element) => element.encode(),
+                Erc4626VaultCalls::MaxRedeem(element) => element.encode(),
+                Erc4626VaultCalls::MaxWithdraw(element) => element.encode(),
+                Erc4626VaultCalls::Mint(element) => element.encode(),
+                Erc4626VaultCalls::Name(element) => element.encode(),
+                Erc4626VaultCalls::Nonces(element) => element.encode(),
+                Erc4626VaultCalls::Permit(element) => element.encode(),
+                Erc4626VaultCalls::PreviewDeposit(element) => element.encode(),
+                Erc4626VaultCalls::PreviewMint(element) => element.encode(),
+                Erc4626VaultCalls::PreviewRedeem(element) => element.encode(),
+                Erc4626VaultCalls::PreviewWithdraw(element) => element.encode(),
+                Erc4626VaultCalls::Redeem(element) => element.encode(),
+                Erc4626VaultCalls::Symbol(element) => element.encode(),
+                Erc4626VaultCalls::TotalAssets(element) => element.encode(),
+                Erc4626VaultCalls::TotalSupply(element) => element.encode(),
+                Erc4626VaultCalls::Transfer(element) => element.encode(),
+                Erc4626VaultCalls::TransferFrom(element) => element.encode(),
+                Erc4626VaultCalls::Withdraw(element) => element.encode(),
+            }
+        }
+    }
+    impl ::std::fmt::Display for Erc4626VaultCalls {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match self {
+                Erc4626VaultCalls::DomainSeparator(element) => element.fmt(f),
+                Erc4626VaultCalls::Allowance(element) => element.fmt(f),
+                Erc4626VaultCalls::Approve(element) => element.fmt(f),
+                Erc4626VaultCalls::Asset(element) => element.fmt(f),
+                Erc4626VaultCalls::BalanceOf(element) => element.fmt(f),
+                Erc4626VaultCalls::ConvertToAssets(element) => element.fmt(f),
+                Erc4626VaultCalls::ConvertToShares(element) => element.fmt(f),
+                Erc4626VaultCalls::Decimals(element) => element.fmt(f),
+                Erc4626VaultCalls::Deposit(element) => element.fmt(f),
+                Erc4626VaultCalls::MaxDeposit(element) => element.fmt(f),
+                Erc4626VaultCalls::MaxMint(element) => element.fmt(f),
+                Erc4626VaultCalls::MaxRedeem(element) => element.fmt(f),
+                Erc4626VaultCalls::MaxWithdraw(element) => element.fmt(f),
+                Erc4626VaultCalls::Mint(element) => element.fmt(f),
+                Erc4626VaultCalls::Name(element) => element.fmt(f),
+                Erc4626VaultCalls::Nonces(element) => element.fmt(f),
+                Erc4626VaultCalls::Permit(element) => element.fmt(f),
+                Erc4626VaultCalls::PreviewDeposit(element) => element.fmt(f),
+                Erc4626VaultCalls::PreviewMint(element) => element.fmt(f),
+                Erc4626VaultCalls::PreviewRedeem(element) => element.fmt(f),
+                Erc4626VaultCalls::PreviewWithdraw(element) => element.fmt(f),
+                Erc4626VaultCalls::Redeem(element) => element.fmt(f),
+                Erc4626VaultCalls::Symbol(element) => element.fmt(f),
+                Erc4626VaultCalls::TotalAssets(element) => element.fmt(f),
+                Erc4626VaultCalls::TotalSupply(element) => element.fmt(f),
+                Erc4626VaultCalls::Transfer(element) => element.fmt(f),
+                Erc4626VaultCalls::TransferFrom(element) => element.fmt(f),
+                Erc4626VaultCalls::Withdraw(element) => element.fmt(f),
+            }
+        }
+    }
+    impl ::std::convert::From<DomainSeparatorCall> for Erc4626VaultCalls {
+        fn from(var: DomainSeparatorCall) -> Self {
+            Erc4626VaultCalls::DomainSeparator(var)
+        }
+    }
+    impl ::std::convert::From<AllowanceCall> for Erc4626VaultCalls {
+        fn from(var: AllowanceCall) -> Self {
+            Erc4626VaultCalls::Allowance(var)
+        }
+    }
+    impl ::std::convert::From<ApproveCall> for Erc4626VaultCalls {
+        fn from(var: ApproveCall) -> Self {
+            Erc4626VaultCalls::Approve(var)
+        }
+    }
+    impl ::std::convert::From<AssetCall> for Erc4626VaultCalls {
+        fn from(var: AssetCall) -> Self {
+            Erc4626VaultCalls::Asset(var)
+        }
+    }
+    impl ::std::convert::From<BalanceOfCall> for Erc4626VaultCalls {
+        fn from(var: BalanceOfCall) -> Self {
+            Erc4626VaultCalls::BalanceOf(var)
+        }
+    }
+    impl ::std::convert::From<ConvertToAssetsCall> for Erc4626VaultCalls {
+        fn from(var: ConvertToAssetsCall) -> Self {
+            Erc4626VaultCalls::ConvertToAssets(var)
+        }
+    }
+    impl ::std::convert::From<ConvertToSharesCall> for Erc4626VaultCalls {
+        fn from(var: ConvertToSharesCall) -> Self {
+            Erc4626VaultCalls::ConvertToShares(var)
+        }
+    }
+    impl ::std::convert::From<DecimalsCall> for Erc4626VaultCalls {
+        fn from(var: DecimalsCall) -> Self {
+            Erc4626VaultCalls::Decimals(var)
+        }
+    }
+    impl ::std::convert::From<DepositCall> for Erc4626VaultCalls {
+        fn from(var: DepositCall) -> Self {
+            Erc4626VaultCalls::Deposit(var)
+        }
+    }
+    impl ::std::convert::From<MaxDepositCall> for Erc4626VaultCalls {
+        fn from(var: MaxDepositCall) -> Self {
+            Erc4626VaultCalls::MaxDeposit(var)
+        }
+    }
+    impl ::std::convert::From<MaxMintCall> for Erc4626VaultCalls {
+        fn from(var: MaxMintCall) -> Self {
+            Erc4626VaultCalls::MaxMint(var)
+        }
+    }
+    impl ::std::convert::From<MaxRedeemCall> for Erc4626VaultCalls {
+        fn from(var: MaxRedeemCall) -> Self {
+            Erc4626VaultCalls::MaxRedeem(var)
+        }
+    }
+    impl ::std::convert::From<MaxWithdrawCall> for Erc4626VaultCalls {
+        fn from(var: MaxWithdrawCall) -> Self {
+            Erc4626VaultCalls::MaxWithdraw(var)
+        }
+    }
+    impl ::std::convert::From<MintCall> for Erc4626VaultCalls {
+        fn from(var: MintCall) -> Self {
+            Erc4626VaultCalls::Mint(var)
+        }
+    }
+    impl ::std::convert::From<NameCall> for Erc4626VaultCalls {
+        fn from(var: NameCall) -> Self {
+            Erc4626VaultCalls::Name(var)
+        }
+    }
+    impl ::std::convert::From<NoncesCall> for Erc4626VaultCalls {
+        fn from(var: NoncesCall) -> Self {
+            Erc4626VaultCalls::Nonces(var)
+        }
+    }
+    impl ::std::convert::From<PermitCall> for Erc4626VaultCalls {
+        fn from(var: PermitCall) -> Self {
+            Erc4626VaultCalls::Permit(var)
+        }
+    }
+    impl ::std::convert::From<PreviewDepositCall> for Erc4626VaultCalls {
+        fn from(var: PreviewDepositCall) -> Self {
+            Erc4626VaultCalls::PreviewDeposit(var)
+        }
+    }
+    impl ::std::convert::From<PreviewMintCall> for Erc4626VaultCalls {
+        fn from(var: PreviewMintCall) -> Self {
+            Erc4626VaultCalls::PreviewMint(var)
+        }
+    }
+    impl ::std::convert::From<PreviewRedeemCall> for Erc4626VaultCalls {
+        fn from(var: PreviewRedeemCall) -> Self {
+            Erc4626VaultCalls::PreviewRedeem(var)
+        }
+    }
+    impl ::std::convert::From<PreviewWithdrawCall> for Erc4626VaultCalls {
+        fn from(var: PreviewWithdrawCall) -> Self {
+            Erc4626VaultCalls::PreviewWithdraw(var)
+        }
+    }
+    impl ::std::convert::From<RedeemCall> for Erc4626VaultCalls {
+        fn from(var: RedeemCall) -> Self {
+            Erc4626VaultCalls::Redeem(var)
+        }
+    }
+    impl ::std::convert::From<SymbolCall> for Erc4626VaultCalls {
+        fn from(var: SymbolCall) -> Self {
+            Erc4626VaultCalls::Symbol(var)
+        }
+    }
+    impl ::std::convert::From<TotalAssetsCall> for Erc4626VaultCalls {
+        fn from(var: TotalAssetsCall) -> Self {
+            Erc4626VaultCalls::TotalAssets(var)
+        }
+    }
+    impl ::std::convert::From<TotalSupplyCall> for Erc4626VaultCalls {
+        fn from(var: TotalSupplyCall) -> Self {
+            Erc4626VaultCalls::TotalSupply(var)
+        }
+    }
+    impl ::std::convert::From<TransferCall> for Erc4626VaultCalls {
+        fn from(var: TransferCall) -> Self {
+            Erc4626VaultCalls::Transfer(var)
+        }
+    }
+    impl ::std::convert::From<TransferFromCall> for Erc4626VaultCalls {
+        fn from(var: TransferFromCall) -> Self {
+            Erc4626VaultCalls::TransferFrom(var)
+        }
+    }
+    impl ::std::convert::From<WithdrawCall> for Erc4626VaultCalls {
+        fn from(var: WithdrawCall) -> Self {
+            Erc4626VaultCalls::Withdraw(var)
+        }
+    }
+}
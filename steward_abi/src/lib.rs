@@ -2,3 +2,5 @@
 // Since this is generated code we just ignore this rule.
 #[allow(clippy::large_enum_variant, clippy::extra_unused_lifetimes)]
 pub mod aave_v2_stablecoin;
+#[allow(clippy::large_enum_variant, clippy::extra_unused_lifetimes)]
+pub mod erc4626_vault;
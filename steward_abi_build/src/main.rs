@@ -1,34 +1,45 @@
 use ethers::contract::Abigen;
 use std::process;
 
+/// Contract name, ABI JSON and output path of each set of bindings
+const CONTRACTS: &[(&str, &str, &str)] = &[
+    (
+        "AaveV2StablecoinCellar",
+        "../steward_abi/AaveV2StablecoinCellar.json",
+        "../steward_abi/src/aave_v2_stablecoin.rs",
+    ),
+    (
+        "Erc4626Vault",
+        "../steward_abi/Erc4626Vault.json",
+        "../steward_abi/src/erc4626_vault.rs",
+    ),
+];
+
 fn main() {
-    // Aave
-    let aave_contract = "AaveV2StablecoinCellar";
-    let abigen = match Abigen::new(
-        aave_contract,
-        format!("../steward_abi/{}.json", aave_contract),
-    ) {
-        Ok(abigen) => abigen,
-        Err(e) => {
-            println!("Could not open {}.json: {}", aave_contract, e);
-            process::exit(1);
-        }
-    };
+    for (contract, abi_path, output) in CONTRACTS {
+        let abigen = match Abigen::new(contract, abi_path) {
+            Ok(abigen) => abigen,
+            Err(e) => {
+                println!("Could not open {}: {}", abi_path, e);
+                process::exit(1);
+            }
+        };
 
-    let abi = match abigen
-        .add_event_derive("serde::Deserialize")
-        .add_event_derive("serde::Serialize")
-        .generate()
-    {
-        Ok(abi) => abi,
-        Err(e) => {
-            println!("Could not generate abi from {}.json: {}", aave_contract, e);
-            process::exit(1);
-        }
-    };
+        let abi = match abigen
+            .add_event_derive("serde::Deserialize")
+            .add_event_derive("serde::Serialize")
+            .generate()
+        {
+            Ok(abi) => abi,
+            Err(e) => {
+                println!("Could not generate abi from {}: {}", abi_path, e);
+                process::exit(1);
+            }
+        };
 
-    match abi.write_to_file("../steward_abi/src/aave_v2_stablecoin.rs") {
-        Ok(_) => (),
-        Err(e) => println!("Error writing aave_v2_stablecoin.rs: {}", e),
+        match abi.write_to_file(output) {
+            Ok(_) => (),
+            Err(e) => println!("Error writing {}: {}", output, e),
+        }
     }
 }
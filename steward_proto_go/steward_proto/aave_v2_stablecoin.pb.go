@@ -261,6 +261,59 @@ func (*AaveV2Stablecoin_SetLiquidityLimit_) isAaveV2Stablecoin_Function() {}
 
 func (*AaveV2Stablecoin_SendFees_) isAaveV2Stablecoin_Function() {}
 
+//
+// Represents a batch of function calls to the Aave V2 Stablecoin cellar, executed atomically in order through a single
+// cork.
+//
+// Represents function `multicall(bytes[] data)`
+type AaveV2StablecoinMulticall struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The function calls to execute, in order
+	FunctionCalls []*AaveV2Stablecoin `protobuf:"bytes,1,rep,name=function_calls,json=functionCalls,proto3" json:"function_calls,omitempty"`
+}
+
+func (x *AaveV2StablecoinMulticall) Reset() {
+	*x = AaveV2StablecoinMulticall{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[1]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2StablecoinMulticall) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2StablecoinMulticall) ProtoMessage() {}
+
+func (x *AaveV2StablecoinMulticall) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[1]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2StablecoinMulticall.ProtoReflect.Descriptor instead.
+func (*AaveV2StablecoinMulticall) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *AaveV2StablecoinMulticall) GetFunctionCalls() []*AaveV2Stablecoin {
+	if x != nil {
+		return x.FunctionCalls
+	}
+	return nil
+}
+
 //
 // Accrue yield, platform fees, and performance fees..
 //
@@ -274,7 +327,7 @@ type AaveV2Stablecoin_Accrue struct {
 func (x *AaveV2Stablecoin_Accrue) Reset() {
 	*x = AaveV2Stablecoin_Accrue{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[1]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[2]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -287,7 +340,7 @@ func (x *AaveV2Stablecoin_Accrue) String() string {
 func (*AaveV2Stablecoin_Accrue) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_Accrue) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[1]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[2]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -316,7 +369,7 @@ type AaveV2Stablecoin_ClaimAndUnstake struct {
 func (x *AaveV2Stablecoin_ClaimAndUnstake) Reset() {
 	*x = AaveV2Stablecoin_ClaimAndUnstake{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[2]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[3]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -329,7 +382,7 @@ func (x *AaveV2Stablecoin_ClaimAndUnstake) String() string {
 func (*AaveV2Stablecoin_ClaimAndUnstake) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_ClaimAndUnstake) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[2]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[3]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -358,7 +411,7 @@ type AaveV2Stablecoin_EnterPosition struct {
 func (x *AaveV2Stablecoin_EnterPosition) Reset() {
 	*x = AaveV2Stablecoin_EnterPosition{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[3]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[4]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -371,7 +424,7 @@ func (x *AaveV2Stablecoin_EnterPosition) String() string {
 func (*AaveV2Stablecoin_EnterPosition) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_EnterPosition) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[3]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[4]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -403,7 +456,7 @@ type AaveV2Stablecoin_EnterPositionWithAssets struct {
 func (x *AaveV2Stablecoin_EnterPositionWithAssets) Reset() {
 	*x = AaveV2Stablecoin_EnterPositionWithAssets{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[4]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[5]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -416,7 +469,7 @@ func (x *AaveV2Stablecoin_EnterPositionWithAssets) String() string {
 func (*AaveV2Stablecoin_EnterPositionWithAssets) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_EnterPositionWithAssets) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[4]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[5]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -452,7 +505,7 @@ type AaveV2Stablecoin_ExitPosition struct {
 func (x *AaveV2Stablecoin_ExitPosition) Reset() {
 	*x = AaveV2Stablecoin_ExitPosition{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[5]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[6]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -465,7 +518,7 @@ func (x *AaveV2Stablecoin_ExitPosition) String() string {
 func (*AaveV2Stablecoin_ExitPosition) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_ExitPosition) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[5]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[6]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -497,7 +550,7 @@ type AaveV2Stablecoin_ExitPositionWithAssets struct {
 func (x *AaveV2Stablecoin_ExitPositionWithAssets) Reset() {
 	*x = AaveV2Stablecoin_ExitPositionWithAssets{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[6]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[7]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -510,7 +563,7 @@ func (x *AaveV2Stablecoin_ExitPositionWithAssets) String() string {
 func (*AaveV2Stablecoin_ExitPositionWithAssets) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_ExitPositionWithAssets) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[6]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[7]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -556,7 +609,7 @@ type AaveV2Stablecoin_Rebalance struct {
 func (x *AaveV2Stablecoin_Rebalance) Reset() {
 	*x = AaveV2Stablecoin_Rebalance{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[7]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[8]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -569,7 +622,7 @@ func (x *AaveV2Stablecoin_Rebalance) String() string {
 func (*AaveV2Stablecoin_Rebalance) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_Rebalance) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[7]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[8]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -622,7 +675,7 @@ type AaveV2Stablecoin_Reinvest struct {
 func (x *AaveV2Stablecoin_Reinvest) Reset() {
 	*x = AaveV2Stablecoin_Reinvest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[8]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[9]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -635,7 +688,7 @@ func (x *AaveV2Stablecoin_Reinvest) String() string {
 func (*AaveV2Stablecoin_Reinvest) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_Reinvest) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[8]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[9]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -673,7 +726,7 @@ type AaveV2Stablecoin_SetAccrualPeriod struct {
 func (x *AaveV2Stablecoin_SetAccrualPeriod) Reset() {
 	*x = AaveV2Stablecoin_SetAccrualPeriod{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[9]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[10]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -686,7 +739,7 @@ func (x *AaveV2Stablecoin_SetAccrualPeriod) String() string {
 func (*AaveV2Stablecoin_SetAccrualPeriod) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_SetAccrualPeriod) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[9]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[10]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -725,7 +778,7 @@ type AaveV2Stablecoin_SetDepositLimit struct {
 func (x *AaveV2Stablecoin_SetDepositLimit) Reset() {
 	*x = AaveV2Stablecoin_SetDepositLimit{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[10]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[11]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -738,7 +791,7 @@ func (x *AaveV2Stablecoin_SetDepositLimit) String() string {
 func (*AaveV2Stablecoin_SetDepositLimit) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_SetDepositLimit) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[10]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[11]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -777,7 +830,7 @@ type AaveV2Stablecoin_SetLiquidityLimit struct {
 func (x *AaveV2Stablecoin_SetLiquidityLimit) Reset() {
 	*x = AaveV2Stablecoin_SetLiquidityLimit{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[11]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[12]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -790,7 +843,7 @@ func (x *AaveV2Stablecoin_SetLiquidityLimit) String() string {
 func (*AaveV2Stablecoin_SetLiquidityLimit) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_SetLiquidityLimit) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[11]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[12]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -826,7 +879,7 @@ type AaveV2Stablecoin_SendFees struct {
 func (x *AaveV2Stablecoin_SendFees) Reset() {
 	*x = AaveV2Stablecoin_SendFees{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[12]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -839,7 +892,7 @@ func (x *AaveV2Stablecoin_SendFees) String() string {
 func (*AaveV2Stablecoin_SendFees) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_SendFees) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[12]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -878,7 +931,7 @@ type AaveV2Stablecoin_Rebalance_SwapParams struct {
 func (x *AaveV2Stablecoin_Rebalance_SwapParams) Reset() {
 	*x = AaveV2Stablecoin_Rebalance_SwapParams{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[14]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -891,7 +944,7 @@ func (x *AaveV2Stablecoin_Rebalance_SwapParams) String() string {
 func (*AaveV2Stablecoin_Rebalance_SwapParams) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_Rebalance_SwapParams) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[14]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1040,9 +1093,15 @@ var file_aave_v2_stablecoin_proto_rawDesc = []byte{
 	0x69, 0x64, 0x69, 0x74, 0x79, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69,
 	0x6d, 0x69, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74,
 	0x1a, 0x0a, 0x0a, 0x08, 0x53, 0x65, 0x6e, 0x64, 0x46, 0x65, 0x65, 0x73, 0x42, 0x0a, 0x0a, 0x08,
-	0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x10, 0x5a, 0x0e, 0x2f, 0x73, 0x74, 0x65,
-	0x77, 0x61, 0x72, 0x64, 0x5f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x33,
+	0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x60, 0x0a, 0x19, 0x41, 0x61, 0x76, 0x65,
+	0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x4d, 0x75, 0x6c, 0x74,
+	0x69, 0x63, 0x61, 0x6c, 0x6c, 0x12, 0x43, 0x0a, 0x0e, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e,
+	0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61, 0x76, 0x65, 0x56,
+	0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x52, 0x0d, 0x66, 0x75, 0x6e,
+	0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x61, 0x6c, 0x6c, 0x73, 0x42, 0x10, 0x5a, 0x0e, 0x2f, 0x73,
+	0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72,
+	0x6f, 0x74, 0x6f, 0x33,
 }
 
 var (
@@ -1057,42 +1116,44 @@ func file_aave_v2_stablecoin_proto_rawDescGZIP() []byte {
 	return file_aave_v2_stablecoin_proto_rawDescData
 }
 
-var file_aave_v2_stablecoin_proto_msgTypes = make([]protoimpl.MessageInfo, 14)
+var file_aave_v2_stablecoin_proto_msgTypes = make([]protoimpl.MessageInfo, 15)
 var file_aave_v2_stablecoin_proto_goTypes = []interface{}{
 	(*AaveV2Stablecoin)(nil),                         // 0: steward.v2.AaveV2Stablecoin
-	(*AaveV2Stablecoin_Accrue)(nil),                  // 1: steward.v2.AaveV2Stablecoin.Accrue
-	(*AaveV2Stablecoin_ClaimAndUnstake)(nil),         // 2: steward.v2.AaveV2Stablecoin.ClaimAndUnstake
-	(*AaveV2Stablecoin_EnterPosition)(nil),           // 3: steward.v2.AaveV2Stablecoin.EnterPosition
-	(*AaveV2Stablecoin_EnterPositionWithAssets)(nil), // 4: steward.v2.AaveV2Stablecoin.EnterPositionWithAssets
-	(*AaveV2Stablecoin_ExitPosition)(nil),            // 5: steward.v2.AaveV2Stablecoin.ExitPosition
-	(*AaveV2Stablecoin_ExitPositionWithAssets)(nil),  // 6: steward.v2.AaveV2Stablecoin.ExitPositionWithAssets
-	(*AaveV2Stablecoin_Rebalance)(nil),               // 7: steward.v2.AaveV2Stablecoin.Rebalance
-	(*AaveV2Stablecoin_Reinvest)(nil),                // 8: steward.v2.AaveV2Stablecoin.Reinvest
-	(*AaveV2Stablecoin_SetAccrualPeriod)(nil),        // 9: steward.v2.AaveV2Stablecoin.SetAccrualPeriod
-	(*AaveV2Stablecoin_SetDepositLimit)(nil),         // 10: steward.v2.AaveV2Stablecoin.SetDepositLimit
-	(*AaveV2Stablecoin_SetLiquidityLimit)(nil),       // 11: steward.v2.AaveV2Stablecoin.SetLiquidityLimit
-	(*AaveV2Stablecoin_SendFees)(nil),                // 12: steward.v2.AaveV2Stablecoin.SendFees
-	(*AaveV2Stablecoin_Rebalance_SwapParams)(nil),    // 13: steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
+	(*AaveV2StablecoinMulticall)(nil),                // 1: steward.v2.AaveV2StablecoinMulticall
+	(*AaveV2Stablecoin_Accrue)(nil),                  // 2: steward.v2.AaveV2Stablecoin.Accrue
+	(*AaveV2Stablecoin_ClaimAndUnstake)(nil),         // 3: steward.v2.AaveV2Stablecoin.ClaimAndUnstake
+	(*AaveV2Stablecoin_EnterPosition)(nil),           // 4: steward.v2.AaveV2Stablecoin.EnterPosition
+	(*AaveV2Stablecoin_EnterPositionWithAssets)(nil), // 5: steward.v2.AaveV2Stablecoin.EnterPositionWithAssets
+	(*AaveV2Stablecoin_ExitPosition)(nil),            // 6: steward.v2.AaveV2Stablecoin.ExitPosition
+	(*AaveV2Stablecoin_ExitPositionWithAssets)(nil),  // 7: steward.v2.AaveV2Stablecoin.ExitPositionWithAssets
+	(*AaveV2Stablecoin_Rebalance)(nil),               // 8: steward.v2.AaveV2Stablecoin.Rebalance
+	(*AaveV2Stablecoin_Reinvest)(nil),                // 9: steward.v2.AaveV2Stablecoin.Reinvest
+	(*AaveV2Stablecoin_SetAccrualPeriod)(nil),        // 10: steward.v2.AaveV2Stablecoin.SetAccrualPeriod
+	(*AaveV2Stablecoin_SetDepositLimit)(nil),         // 11: steward.v2.AaveV2Stablecoin.SetDepositLimit
+	(*AaveV2Stablecoin_SetLiquidityLimit)(nil),       // 12: steward.v2.AaveV2Stablecoin.SetLiquidityLimit
+	(*AaveV2Stablecoin_SendFees)(nil),                // 13: steward.v2.AaveV2Stablecoin.SendFees
+	(*AaveV2Stablecoin_Rebalance_SwapParams)(nil),    // 14: steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
 }
 var file_aave_v2_stablecoin_proto_depIdxs = []int32{
-	1,  // 0: steward.v2.AaveV2Stablecoin.accrue:type_name -> steward.v2.AaveV2Stablecoin.Accrue
-	2,  // 1: steward.v2.AaveV2Stablecoin.claim_and_unstake:type_name -> steward.v2.AaveV2Stablecoin.ClaimAndUnstake
-	3,  // 2: steward.v2.AaveV2Stablecoin.enter_position:type_name -> steward.v2.AaveV2Stablecoin.EnterPosition
-	4,  // 3: steward.v2.AaveV2Stablecoin.enter_position_with_assets:type_name -> steward.v2.AaveV2Stablecoin.EnterPositionWithAssets
-	5,  // 4: steward.v2.AaveV2Stablecoin.exit_position:type_name -> steward.v2.AaveV2Stablecoin.ExitPosition
-	6,  // 5: steward.v2.AaveV2Stablecoin.exit_position_with_assets:type_name -> steward.v2.AaveV2Stablecoin.ExitPositionWithAssets
-	7,  // 6: steward.v2.AaveV2Stablecoin.rebalance:type_name -> steward.v2.AaveV2Stablecoin.Rebalance
-	8,  // 7: steward.v2.AaveV2Stablecoin.reinvest:type_name -> steward.v2.AaveV2Stablecoin.Reinvest
-	9,  // 8: steward.v2.AaveV2Stablecoin.set_accrual_period:type_name -> steward.v2.AaveV2Stablecoin.SetAccrualPeriod
-	10, // 9: steward.v2.AaveV2Stablecoin.set_deposit_limit:type_name -> steward.v2.AaveV2Stablecoin.SetDepositLimit
-	11, // 10: steward.v2.AaveV2Stablecoin.set_liquidity_limit:type_name -> steward.v2.AaveV2Stablecoin.SetLiquidityLimit
-	12, // 11: steward.v2.AaveV2Stablecoin.send_fees:type_name -> steward.v2.AaveV2Stablecoin.SendFees
-	13, // 12: steward.v2.AaveV2Stablecoin.Rebalance.swap_params:type_name -> steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
-	13, // [13:13] is the sub-list for method output_type
-	13, // [13:13] is the sub-list for method input_type
-	13, // [13:13] is the sub-list for extension type_name
-	13, // [13:13] is the sub-list for extension extendee
-	0,  // [0:13] is the sub-list for field type_name
+	2,  // 0: steward.v2.AaveV2Stablecoin.accrue:type_name -> steward.v2.AaveV2Stablecoin.Accrue
+	3,  // 1: steward.v2.AaveV2Stablecoin.claim_and_unstake:type_name -> steward.v2.AaveV2Stablecoin.ClaimAndUnstake
+	4,  // 2: steward.v2.AaveV2Stablecoin.enter_position:type_name -> steward.v2.AaveV2Stablecoin.EnterPosition
+	5,  // 3: steward.v2.AaveV2Stablecoin.enter_position_with_assets:type_name -> steward.v2.AaveV2Stablecoin.EnterPositionWithAssets
+	6,  // 4: steward.v2.AaveV2Stablecoin.exit_position:type_name -> steward.v2.AaveV2Stablecoin.ExitPosition
+	7,  // 5: steward.v2.AaveV2Stablecoin.exit_position_with_assets:type_name -> steward.v2.AaveV2Stablecoin.ExitPositionWithAssets
+	8,  // 6: steward.v2.AaveV2Stablecoin.rebalance:type_name -> steward.v2.AaveV2Stablecoin.Rebalance
+	9,  // 7: steward.v2.AaveV2Stablecoin.reinvest:type_name -> steward.v2.AaveV2Stablecoin.Reinvest
+	10, // 8: steward.v2.AaveV2Stablecoin.set_accrual_period:type_name -> steward.v2.AaveV2Stablecoin.SetAccrualPeriod
+	11, // 9: steward.v2.AaveV2Stablecoin.set_deposit_limit:type_name -> steward.v2.AaveV2Stablecoin.SetDepositLimit
+	12, // 10: steward.v2.AaveV2Stablecoin.set_liquidity_limit:type_name -> steward.v2.AaveV2Stablecoin.SetLiquidityLimit
+	13, // 11: steward.v2.AaveV2Stablecoin.send_fees:type_name -> steward.v2.AaveV2Stablecoin.SendFees
+	0,  // 12: steward.v2.AaveV2StablecoinMulticall.function_calls:type_name -> steward.v2.AaveV2Stablecoin
+	14, // 13: steward.v2.AaveV2Stablecoin.Rebalance.swap_params:type_name -> steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
+	14, // [14:14] is the sub-list for method output_type
+	14, // [14:14] is the sub-list for method input_type
+	14, // [14:14] is the sub-list for extension type_name
+	14, // [14:14] is the sub-list for extension extendee
+	0,  // [0:14] is the sub-list for field type_name
 }
 
 func init() { file_aave_v2_stablecoin_proto_init() }
@@ -1114,7 +1175,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_Accrue); i {
+			switch v := v.(*AaveV2StablecoinMulticall); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1126,7 +1187,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_ClaimAndUnstake); i {
+			switch v := v.(*AaveV2Stablecoin_Accrue); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1138,7 +1199,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_EnterPosition); i {
+			switch v := v.(*AaveV2Stablecoin_ClaimAndUnstake); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1150,7 +1211,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_EnterPositionWithAssets); i {
+			switch v := v.(*AaveV2Stablecoin_EnterPosition); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1162,7 +1223,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_ExitPosition); i {
+			switch v := v.(*AaveV2Stablecoin_EnterPositionWithAssets); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1174,7 +1235,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_ExitPositionWithAssets); i {
+			switch v := v.(*AaveV2Stablecoin_ExitPosition); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1186,7 +1247,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_Rebalance); i {
+			switch v := v.(*AaveV2Stablecoin_ExitPositionWithAssets); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1198,7 +1259,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_Reinvest); i {
+			switch v := v.(*AaveV2Stablecoin_Rebalance); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1210,7 +1271,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_SetAccrualPeriod); i {
+			switch v := v.(*AaveV2Stablecoin_Reinvest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1222,7 +1283,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_SetDepositLimit); i {
+			switch v := v.(*AaveV2Stablecoin_SetAccrualPeriod); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1234,7 +1295,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_SetLiquidityLimit); i {
+			switch v := v.(*AaveV2Stablecoin_SetDepositLimit); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1246,7 +1307,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AaveV2Stablecoin_SendFees); i {
+			switch v := v.(*AaveV2Stablecoin_SetLiquidityLimit); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1258,6 +1319,18 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_SendFees); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*AaveV2Stablecoin_Rebalance_SwapParams); i {
 			case 0:
 				return &v.state
@@ -1290,7 +1363,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_aave_v2_stablecoin_proto_rawDesc,
 			NumEnums:      0,
-			NumMessages:   14,
+			NumMessages:   15,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
@@ -0,0 +1,377 @@
+//
+// This is Steward's Strategy Provider API for Cellar contracts implementing the ERC-4626 tokenized vault standard:
+// https://eips.ethereum.org/EIPS/eip-4626
+//
+
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.26.0
+// 	protoc        v3.19.4
+// source: erc4626_vault.proto
+
+package steward_proto
+
+import (
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+	sync "sync"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+//
+// Represents a function call to an ERC-4626 vault cellar. Corks are executed with the Gravity contract as the caller, so
+// only functions that return assets to the cellar are supported.
+type Erc4626Vault struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The function you wish to execute on the target cellar
+	//
+	// Types that are assignable to Function:
+	//	*Erc4626Vault_Withdraw_
+	//	*Erc4626Vault_Redeem_
+	Function isErc4626Vault_Function `protobuf_oneof:"function"`
+}
+
+func (x *Erc4626Vault) Reset() {
+	*x = Erc4626Vault{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_erc4626_vault_proto_msgTypes[0]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Erc4626Vault) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Erc4626Vault) ProtoMessage() {}
+
+func (x *Erc4626Vault) ProtoReflect() protoreflect.Message {
+	mi := &file_erc4626_vault_proto_msgTypes[0]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Erc4626Vault.ProtoReflect.Descriptor instead.
+func (*Erc4626Vault) Descriptor() ([]byte, []int) {
+	return file_erc4626_vault_proto_rawDescGZIP(), []int{0}
+}
+
+func (m *Erc4626Vault) GetFunction() isErc4626Vault_Function {
+	if m != nil {
+		return m.Function
+	}
+	return nil
+}
+
+func (x *Erc4626Vault) GetWithdraw() *Erc4626Vault_Withdraw {
+	if x, ok := x.GetFunction().(*Erc4626Vault_Withdraw_); ok {
+		return x.Withdraw
+	}
+	return nil
+}
+
+func (x *Erc4626Vault) GetRedeem() *Erc4626Vault_Redeem {
+	if x, ok := x.GetFunction().(*Erc4626Vault_Redeem_); ok {
+		return x.Redeem
+	}
+	return nil
+}
+
+type isErc4626Vault_Function interface {
+	isErc4626Vault_Function()
+}
+
+type Erc4626Vault_Withdraw_ struct {
+	// Represents function `withdraw(uint256 assets, address receiver, address owner)`
+	Withdraw *Erc4626Vault_Withdraw `protobuf:"bytes,1,opt,name=withdraw,proto3,oneof"`
+}
+
+type Erc4626Vault_Redeem_ struct {
+	// Represents function `redeem(uint256 shares, address receiver, address owner)`
+	Redeem *Erc4626Vault_Redeem `protobuf:"bytes,2,opt,name=redeem,proto3,oneof"`
+}
+
+func (*Erc4626Vault_Withdraw_) isErc4626Vault_Function() {}
+
+func (*Erc4626Vault_Redeem_) isErc4626Vault_Function() {}
+
+//
+// Burns shares from the owner and sends exactly `assets` to the receiver.
+//
+// Represents function `withdraw(uint256 assets, address receiver, address owner)`
+type Erc4626Vault_Withdraw struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Amount of assets to withdraw. Must be parsable as an unsigned 256-bit integer.
+	Assets string `protobuf:"bytes,1,opt,name=assets,proto3" json:"assets,omitempty"`
+	// Address that will receive the withdrawn assets. Must be the target cellar.
+	Receiver string `protobuf:"bytes,2,opt,name=receiver,proto3" json:"receiver,omitempty"`
+	// Address whose shares will be burned. Must not be the Gravity contract.
+	Owner string `protobuf:"bytes,3,opt,name=owner,proto3" json:"owner,omitempty"`
+}
+
+func (x *Erc4626Vault_Withdraw) Reset() {
+	*x = Erc4626Vault_Withdraw{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_erc4626_vault_proto_msgTypes[1]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Erc4626Vault_Withdraw) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Erc4626Vault_Withdraw) ProtoMessage() {}
+
+func (x *Erc4626Vault_Withdraw) ProtoReflect() protoreflect.Message {
+	mi := &file_erc4626_vault_proto_msgTypes[1]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Erc4626Vault_Withdraw.ProtoReflect.Descriptor instead.
+func (*Erc4626Vault_Withdraw) Descriptor() ([]byte, []int) {
+	return file_erc4626_vault_proto_rawDescGZIP(), []int{0, 0}
+}
+
+func (x *Erc4626Vault_Withdraw) GetAssets() string {
+	if x != nil {
+		return x.Assets
+	}
+	return ""
+}
+
+func (x *Erc4626Vault_Withdraw) GetReceiver() string {
+	if x != nil {
+		return x.Receiver
+	}
+	return ""
+}
+
+func (x *Erc4626Vault_Withdraw) GetOwner() string {
+	if x != nil {
+		return x.Owner
+	}
+	return ""
+}
+
+//
+// Burns exactly `shares` from the owner and sends the resulting assets to the receiver.
+//
+// Represents function `redeem(uint256 shares, address receiver, address owner)`
+type Erc4626Vault_Redeem struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Amount of shares to redeem. Must be parsable as an unsigned 256-bit integer.
+	Shares string `protobuf:"bytes,1,opt,name=shares,proto3" json:"shares,omitempty"`
+	// Address that will receive the redeemed assets. Must be the target cellar.
+	Receiver string `protobuf:"bytes,2,opt,name=receiver,proto3" json:"receiver,omitempty"`
+	// Address whose shares will be burned. Must not be the Gravity contract.
+	Owner string `protobuf:"bytes,3,opt,name=owner,proto3" json:"owner,omitempty"`
+}
+
+func (x *Erc4626Vault_Redeem) Reset() {
+	*x = Erc4626Vault_Redeem{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_erc4626_vault_proto_msgTypes[2]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Erc4626Vault_Redeem) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Erc4626Vault_Redeem) ProtoMessage() {}
+
+func (x *Erc4626Vault_Redeem) ProtoReflect() protoreflect.Message {
+	mi := &file_erc4626_vault_proto_msgTypes[2]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Erc4626Vault_Redeem.ProtoReflect.Descriptor instead.
+func (*Erc4626Vault_Redeem) Descriptor() ([]byte, []int) {
+	return file_erc4626_vault_proto_rawDescGZIP(), []int{0, 1}
+}
+
+func (x *Erc4626Vault_Redeem) GetShares() string {
+	if x != nil {
+		return x.Shares
+	}
+	return ""
+}
+
+func (x *Erc4626Vault_Redeem) GetReceiver() string {
+	if x != nil {
+		return x.Receiver
+	}
+	return ""
+}
+
+func (x *Erc4626Vault_Redeem) GetOwner() string {
+	if x != nil {
+		return x.Owner
+	}
+	return ""
+}
+
+var File_erc4626_vault_proto protoreflect.FileDescriptor
+
+var file_erc4626_vault_proto_rawDesc = []byte{
+	0x0a, 0x13, 0x65, 0x72, 0x63, 0x34, 0x36, 0x32, 0x36, 0x5f, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x0a, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76,
+	0x32, 0x22, 0xc0, 0x02, 0x0a, 0x0c, 0x45, 0x72, 0x63, 0x34, 0x36, 0x32, 0x36, 0x56, 0x61, 0x75,
+	0x6c, 0x74, 0x12, 0x3f, 0x0a, 0x08, 0x77, 0x69, 0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76,
+	0x32, 0x2e, 0x45, 0x72, 0x63, 0x34, 0x36, 0x32, 0x36, 0x56, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x57,
+	0x69, 0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x48, 0x00, 0x52, 0x08, 0x77, 0x69, 0x74, 0x68, 0x64,
+	0x72, 0x61, 0x77, 0x12, 0x39, 0x0a, 0x06, 0x72, 0x65, 0x64, 0x65, 0x65, 0x6d, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32,
+	0x2e, 0x45, 0x72, 0x63, 0x34, 0x36, 0x32, 0x36, 0x56, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x52, 0x65,
+	0x64, 0x65, 0x65, 0x6d, 0x48, 0x00, 0x52, 0x06, 0x72, 0x65, 0x64, 0x65, 0x65, 0x6d, 0x1a, 0x54,
+	0x0a, 0x08, 0x57, 0x69, 0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x12, 0x16, 0x0a, 0x06, 0x61, 0x73,
+	0x73, 0x65, 0x74, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x61, 0x73, 0x73, 0x65,
+	0x74, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x12, 0x14,
+	0x0a, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x6f,
+	0x77, 0x6e, 0x65, 0x72, 0x1a, 0x52, 0x0a, 0x06, 0x52, 0x65, 0x64, 0x65, 0x65, 0x6d, 0x12, 0x16,
+	0x0a, 0x06, 0x73, 0x68, 0x61, 0x72, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06,
+	0x73, 0x68, 0x61, 0x72, 0x65, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76,
+	0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76,
+	0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x42, 0x0a, 0x0a, 0x08, 0x66, 0x75, 0x6e, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x42, 0x10, 0x5a, 0x0e, 0x2f, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64,
+	0x5f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+}
+
+var (
+	file_erc4626_vault_proto_rawDescOnce sync.Once
+	file_erc4626_vault_proto_rawDescData = file_erc4626_vault_proto_rawDesc
+)
+
+func file_erc4626_vault_proto_rawDescGZIP() []byte {
+	file_erc4626_vault_proto_rawDescOnce.Do(func() {
+		file_erc4626_vault_proto_rawDescData = protoimpl.X.CompressGZIP(file_erc4626_vault_proto_rawDescData)
+	})
+	return file_erc4626_vault_proto_rawDescData
+}
+
+var file_erc4626_vault_proto_msgTypes = make([]protoimpl.MessageInfo, 3)
+var file_erc4626_vault_proto_goTypes = []interface{}{
+	(*Erc4626Vault)(nil),          // 0: steward.v2.Erc4626Vault
+	(*Erc4626Vault_Withdraw)(nil), // 1: steward.v2.Erc4626Vault.Withdraw
+	(*Erc4626Vault_Redeem)(nil),   // 2: steward.v2.Erc4626Vault.Redeem
+}
+var file_erc4626_vault_proto_depIdxs = []int32{
+	1, // 0: steward.v2.Erc4626Vault.withdraw:type_name -> steward.v2.Erc4626Vault.Withdraw
+	2, // 1: steward.v2.Erc4626Vault.redeem:type_name -> steward.v2.Erc4626Vault.Redeem
+	2, // [2:2] is the sub-list for method output_type
+	2, // [2:2] is the sub-list for method input_type
+	2, // [2:2] is the sub-list for extension type_name
+	2, // [2:2] is the sub-list for extension extendee
+	0, // [0:2] is the sub-list for field type_name
+}
+
+func init() { file_erc4626_vault_proto_init() }
+func file_erc4626_vault_proto_init() {
+	if File_erc4626_vault_proto != nil {
+		return
+	}
+	if !protoimpl.UnsafeEnabled {
+		file_erc4626_vault_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Erc4626Vault); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_erc4626_vault_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Erc4626Vault_Withdraw); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_erc4626_vault_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Erc4626Vault_Redeem); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+	}
+	file_erc4626_vault_proto_msgTypes[0].OneofWrappers = []interface{}{
+		(*Erc4626Vault_Withdraw_)(nil),
+		(*Erc4626Vault_Redeem_)(nil),
+	}
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: file_erc4626_vault_proto_rawDesc,
+			NumEnums:      0,
+			NumMessages:   3,
+			NumExtensions: 0,
+			NumServices:   0,
+		},
+		GoTypes:           file_erc4626_vault_proto_goTypes,
+		DependencyIndexes: file_erc4626_vault_proto_depIdxs,
+		MessageInfos:      file_erc4626_vault_proto_msgTypes,
+	}.Build()
+	File_erc4626_vault_proto = out.File
+	file_erc4626_vault_proto_rawDesc = nil
+	file_erc4626_vault_proto_goTypes = nil
+	file_erc4626_vault_proto_depIdxs = nil
+}
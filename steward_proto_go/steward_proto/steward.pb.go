@@ -26,6 +26,72 @@ const (
 	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
 )
 
+//
+// Lifecycle stages of a cork
+type CorkStatus int32
+
+const (
+	// Unused default value
+	CorkStatus_CORK_STATUS_UNSPECIFIED CorkStatus = 0
+	// The submission transaction failed
+	CorkStatus_CORK_STATUS_FAILED CorkStatus = 1
+	// The submission transaction was included in a block
+	CorkStatus_CORK_STATUS_INCLUDED CorkStatus = 2
+	// The cork is awaiting the end of the cork module's current vote period
+	CorkStatus_CORK_STATUS_PENDING CorkStatus = 3
+	// The cork was approved by the validator set and handed to the Gravity bridge to be relayed to Ethereum
+	CorkStatus_CORK_STATUS_BRIDGED CorkStatus = 4
+	// The cork was not found in the submission transaction, the cork module, or the Gravity bridge
+	CorkStatus_CORK_STATUS_NOT_FOUND CorkStatus = 5
+)
+
+// Enum value maps for CorkStatus.
+var (
+	CorkStatus_name = map[int32]string{
+		0: "CORK_STATUS_UNSPECIFIED",
+		1: "CORK_STATUS_FAILED",
+		2: "CORK_STATUS_INCLUDED",
+		3: "CORK_STATUS_PENDING",
+		4: "CORK_STATUS_BRIDGED",
+		5: "CORK_STATUS_NOT_FOUND",
+	}
+	CorkStatus_value = map[string]int32{
+		"CORK_STATUS_UNSPECIFIED": 0,
+		"CORK_STATUS_FAILED":      1,
+		"CORK_STATUS_INCLUDED":    2,
+		"CORK_STATUS_PENDING":     3,
+		"CORK_STATUS_BRIDGED":     4,
+		"CORK_STATUS_NOT_FOUND":   5,
+	}
+)
+
+func (x CorkStatus) Enum() *CorkStatus {
+	p := new(CorkStatus)
+	*p = x
+	return p
+}
+
+func (x CorkStatus) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (CorkStatus) Descriptor() protoreflect.EnumDescriptor {
+	return file_steward_proto_enumTypes[0].Descriptor()
+}
+
+func (CorkStatus) Type() protoreflect.EnumType {
+	return &file_steward_proto_enumTypes[0]
+}
+
+func (x CorkStatus) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use CorkStatus.Descriptor instead.
+func (CorkStatus) EnumDescriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{0}
+}
+
 //
 // Represents a single function call on a particular Cellar
 type SubmitRequest struct {
@@ -39,7 +105,11 @@ type SubmitRequest struct {
 	//
 	// Types that are assignable to CallData:
 	//	*SubmitRequest_AaveV2Stablecoin
+	//	*SubmitRequest_Erc4626Vault
+	//	*SubmitRequest_AaveV2StablecoinMulticall
 	CallData isSubmitRequest_CallData `protobuf_oneof:"call_data"`
+	// Optional key identifying this submission. A retried request with the same key from the same client returns the result of the original request instead of submitting the cork again.
+	IdempotencyKey string `protobuf:"bytes,5,opt,name=idempotency_key,json=idempotencyKey,proto3" json:"idempotency_key,omitempty"`
 }
 
 func (x *SubmitRequest) Reset() {
@@ -95,6 +165,27 @@ func (x *SubmitRequest) GetAaveV2Stablecoin() *AaveV2Stablecoin {
 	return nil
 }
 
+func (x *SubmitRequest) GetErc4626Vault() *Erc4626Vault {
+	if x, ok := x.GetCallData().(*SubmitRequest_Erc4626Vault); ok {
+		return x.Erc4626Vault
+	}
+	return nil
+}
+
+func (x *SubmitRequest) GetAaveV2StablecoinMulticall() *AaveV2StablecoinMulticall {
+	if x, ok := x.GetCallData().(*SubmitRequest_AaveV2StablecoinMulticall); ok {
+		return x.AaveV2StablecoinMulticall
+	}
+	return nil
+}
+
+func (x *SubmitRequest) GetIdempotencyKey() string {
+	if x != nil {
+		return x.IdempotencyKey
+	}
+	return ""
+}
+
 type isSubmitRequest_CallData interface {
 	isSubmitRequest_CallData()
 }
@@ -103,12 +194,35 @@ type SubmitRequest_AaveV2Stablecoin struct {
 	AaveV2Stablecoin *AaveV2Stablecoin `protobuf:"bytes,2,opt,name=aave_v2_stablecoin,json=aaveV2Stablecoin,proto3,oneof"`
 }
 
+type SubmitRequest_Erc4626Vault struct {
+	Erc4626Vault *Erc4626Vault `protobuf:"bytes,3,opt,name=erc4626_vault,json=erc4626Vault,proto3,oneof"`
+}
+
+type SubmitRequest_AaveV2StablecoinMulticall struct {
+	AaveV2StablecoinMulticall *AaveV2StablecoinMulticall `protobuf:"bytes,4,opt,name=aave_v2_stablecoin_multicall,json=aaveV2StablecoinMulticall,proto3,oneof"`
+}
+
 func (*SubmitRequest_AaveV2Stablecoin) isSubmitRequest_CallData() {}
 
+func (*SubmitRequest_Erc4626Vault) isSubmitRequest_CallData() {}
+
+func (*SubmitRequest_AaveV2StablecoinMulticall) isSubmitRequest_CallData() {}
+
+//
+// Represents the result of a cork submission
 type SubmitResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	// Hex-encoded keccak256 hash of the target contract address and encoded contract call. The same value is used by the Gravity bridge as the invalidation scope of the resulting contract call.
+	CorkId string `protobuf:"bytes,1,opt,name=cork_id,json=corkId,proto3" json:"cork_id,omitempty"`
+	// Hash of the transaction that submitted the cork to Sommelier
+	TxHash string `protobuf:"bytes,2,opt,name=tx_hash,json=txHash,proto3" json:"tx_hash,omitempty"`
+	// Height of the block in which the transaction was included
+	Height int64 `protobuf:"varint,3,opt,name=height,proto3" json:"height,omitempty"`
+	// Result code of the transaction. Zero indicates success.
+	Code uint32 `protobuf:"varint,4,opt,name=code,proto3" json:"code,omitempty"`
 }
 
 func (x *SubmitResponse) Reset() {
@@ -143,27 +257,713 @@ func (*SubmitResponse) Descriptor() ([]byte, []int) {
 	return file_steward_proto_rawDescGZIP(), []int{1}
 }
 
+func (x *SubmitResponse) GetCorkId() string {
+	if x != nil {
+		return x.CorkId
+	}
+	return ""
+}
+
+func (x *SubmitResponse) GetTxHash() string {
+	if x != nil {
+		return x.TxHash
+	}
+	return ""
+}
+
+func (x *SubmitResponse) GetHeight() int64 {
+	if x != nil {
+		return x.Height
+	}
+	return 0
+}
+
+func (x *SubmitResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+//
+// Represents a single function call on a particular Cellar, scheduled for a block height
+type ScheduleRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The contract call, as it would be passed to Submit. The idempotency key is ignored.
+	Request *SubmitRequest `protobuf:"bytes,1,opt,name=request,proto3" json:"request,omitempty"`
+	// The Sommelier block height at which the cork module will submit the cork. Must be in the future and no more than the Steward's configured horizon ahead of the current height.
+	BlockHeight uint64 `protobuf:"varint,2,opt,name=block_height,json=blockHeight,proto3" json:"block_height,omitempty"`
+}
+
+func (x *ScheduleRequest) Reset() {
+	*x = ScheduleRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[2]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ScheduleRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ScheduleRequest) ProtoMessage() {}
+
+func (x *ScheduleRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[2]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ScheduleRequest.ProtoReflect.Descriptor instead.
+func (*ScheduleRequest) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *ScheduleRequest) GetRequest() *SubmitRequest {
+	if x != nil {
+		return x.Request
+	}
+	return nil
+}
+
+func (x *ScheduleRequest) GetBlockHeight() uint64 {
+	if x != nil {
+		return x.BlockHeight
+	}
+	return 0
+}
+
+//
+// Represents the result of scheduling a cork
+type ScheduleResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Hex-encoded keccak256 hash of the target contract address and encoded contract call
+	CorkId string `protobuf:"bytes,1,opt,name=cork_id,json=corkId,proto3" json:"cork_id,omitempty"`
+	// Hash of the transaction that scheduled the cork on Sommelier
+	TxHash string `protobuf:"bytes,2,opt,name=tx_hash,json=txHash,proto3" json:"tx_hash,omitempty"`
+	// Height of the block in which the transaction was included
+	Height int64 `protobuf:"varint,3,opt,name=height,proto3" json:"height,omitempty"`
+	// Result code of the transaction. Zero indicates success.
+	Code uint32 `protobuf:"varint,4,opt,name=code,proto3" json:"code,omitempty"`
+	// The block height the cork is scheduled for
+	BlockHeight uint64 `protobuf:"varint,5,opt,name=block_height,json=blockHeight,proto3" json:"block_height,omitempty"`
+}
+
+func (x *ScheduleResponse) Reset() {
+	*x = ScheduleResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[3]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ScheduleResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ScheduleResponse) ProtoMessage() {}
+
+func (x *ScheduleResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[3]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ScheduleResponse.ProtoReflect.Descriptor instead.
+func (*ScheduleResponse) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *ScheduleResponse) GetCorkId() string {
+	if x != nil {
+		return x.CorkId
+	}
+	return ""
+}
+
+func (x *ScheduleResponse) GetTxHash() string {
+	if x != nil {
+		return x.TxHash
+	}
+	return ""
+}
+
+func (x *ScheduleResponse) GetHeight() int64 {
+	if x != nil {
+		return x.Height
+	}
+	return 0
+}
+
+func (x *ScheduleResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
+func (x *ScheduleResponse) GetBlockHeight() uint64 {
+	if x != nil {
+		return x.BlockHeight
+	}
+	return 0
+}
+
+//
+// Represents a query for pending scheduled corks
+type ListScheduledCorksRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// (Optional) Only list corks targeting this Cellar
+	CellarId string `protobuf:"bytes,1,opt,name=cellar_id,json=cellarId,proto3" json:"cellar_id,omitempty"`
+}
+
+func (x *ListScheduledCorksRequest) Reset() {
+	*x = ListScheduledCorksRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[4]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListScheduledCorksRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListScheduledCorksRequest) ProtoMessage() {}
+
+func (x *ListScheduledCorksRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[4]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListScheduledCorksRequest.ProtoReflect.Descriptor instead.
+func (*ListScheduledCorksRequest) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *ListScheduledCorksRequest) GetCellarId() string {
+	if x != nil {
+		return x.CellarId
+	}
+	return ""
+}
+
+//
+// Represents the pending scheduled corks, ordered by block height
+type ListScheduledCorksResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Corks []*ScheduledCork `protobuf:"bytes,1,rep,name=corks,proto3" json:"corks,omitempty"`
+}
+
+func (x *ListScheduledCorksResponse) Reset() {
+	*x = ListScheduledCorksResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[5]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListScheduledCorksResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListScheduledCorksResponse) ProtoMessage() {}
+
+func (x *ListScheduledCorksResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[5]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListScheduledCorksResponse.ProtoReflect.Descriptor instead.
+func (*ListScheduledCorksResponse) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *ListScheduledCorksResponse) GetCorks() []*ScheduledCork {
+	if x != nil {
+		return x.Corks
+	}
+	return nil
+}
+
+//
+// Represents a cork scheduled for a block height
+type ScheduledCork struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Hex-encoded keccak256 hash of the target contract address and encoded contract call
+	CorkId string `protobuf:"bytes,1,opt,name=cork_id,json=corkId,proto3" json:"cork_id,omitempty"`
+	// The ID of the target Cellar
+	CellarId string `protobuf:"bytes,2,opt,name=cellar_id,json=cellarId,proto3" json:"cellar_id,omitempty"`
+	// The block height at which the cork module will submit the cork
+	BlockHeight uint64 `protobuf:"varint,3,opt,name=block_height,json=blockHeight,proto3" json:"block_height,omitempty"`
+	// The ABI-encoded contract call
+	EncodedContractCall []byte `protobuf:"bytes,4,opt,name=encoded_contract_call,json=encodedContractCall,proto3" json:"encoded_contract_call,omitempty"`
+	// Signature of the encoded function, e.g. `initiateShutdown(bool)`. Empty if the call could not be decoded.
+	FunctionSignature string `protobuf:"bytes,5,opt,name=function_signature,json=functionSignature,proto3" json:"function_signature,omitempty"`
+	// The decoded arguments as a comma separated list. Empty if the call could not be decoded.
+	Arguments string `protobuf:"bytes,6,opt,name=arguments,proto3" json:"arguments,omitempty"`
+}
+
+func (x *ScheduledCork) Reset() {
+	*x = ScheduledCork{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[6]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ScheduledCork) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ScheduledCork) ProtoMessage() {}
+
+func (x *ScheduledCork) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[6]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ScheduledCork.ProtoReflect.Descriptor instead.
+func (*ScheduledCork) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *ScheduledCork) GetCorkId() string {
+	if x != nil {
+		return x.CorkId
+	}
+	return ""
+}
+
+func (x *ScheduledCork) GetCellarId() string {
+	if x != nil {
+		return x.CellarId
+	}
+	return ""
+}
+
+func (x *ScheduledCork) GetBlockHeight() uint64 {
+	if x != nil {
+		return x.BlockHeight
+	}
+	return 0
+}
+
+func (x *ScheduledCork) GetEncodedContractCall() []byte {
+	if x != nil {
+		return x.EncodedContractCall
+	}
+	return nil
+}
+
+func (x *ScheduledCork) GetFunctionSignature() string {
+	if x != nil {
+		return x.FunctionSignature
+	}
+	return ""
+}
+
+func (x *ScheduledCork) GetArguments() string {
+	if x != nil {
+		return x.Arguments
+	}
+	return ""
+}
+
+//
+// Represents the cork that Submit would send for a given request
+type SimulateResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Hex-encoded keccak256 hash of the target contract address and encoded contract call
+	CorkId string `protobuf:"bytes,1,opt,name=cork_id,json=corkId,proto3" json:"cork_id,omitempty"`
+	// The address of the contract the cork targets
+	TargetContractAddress string `protobuf:"bytes,2,opt,name=target_contract_address,json=targetContractAddress,proto3" json:"target_contract_address,omitempty"`
+	// The ABI-encoded contract call
+	EncodedContractCall []byte `protobuf:"bytes,3,opt,name=encoded_contract_call,json=encodedContractCall,proto3" json:"encoded_contract_call,omitempty"`
+	// Signature of the encoded function, e.g. `rebalance(address[9],uint256[3][4],uint256)`
+	FunctionSignature string `protobuf:"bytes,4,opt,name=function_signature,json=functionSignature,proto3" json:"function_signature,omitempty"`
+}
+
+func (x *SimulateResponse) Reset() {
+	*x = SimulateResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[7]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SimulateResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SimulateResponse) ProtoMessage() {}
+
+func (x *SimulateResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[7]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SimulateResponse.ProtoReflect.Descriptor instead.
+func (*SimulateResponse) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{7}
+}
+
+func (x *SimulateResponse) GetCorkId() string {
+	if x != nil {
+		return x.CorkId
+	}
+	return ""
+}
+
+func (x *SimulateResponse) GetTargetContractAddress() string {
+	if x != nil {
+		return x.TargetContractAddress
+	}
+	return ""
+}
+
+func (x *SimulateResponse) GetEncodedContractCall() []byte {
+	if x != nil {
+		return x.EncodedContractCall
+	}
+	return nil
+}
+
+func (x *SimulateResponse) GetFunctionSignature() string {
+	if x != nil {
+		return x.FunctionSignature
+	}
+	return ""
+}
+
+//
+// Represents a query for the status of a cork
+type GetCorkStatusRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The cork ID returned by Submit
+	CorkId string `protobuf:"bytes,1,opt,name=cork_id,json=corkId,proto3" json:"cork_id,omitempty"`
+	// (Optional) The transaction hash returned by Submit. Required to report CORK_STATUS_INCLUDED and CORK_STATUS_FAILED.
+	TxHash string `protobuf:"bytes,2,opt,name=tx_hash,json=txHash,proto3" json:"tx_hash,omitempty"`
+}
+
+func (x *GetCorkStatusRequest) Reset() {
+	*x = GetCorkStatusRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[8]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetCorkStatusRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetCorkStatusRequest) ProtoMessage() {}
+
+func (x *GetCorkStatusRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[8]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetCorkStatusRequest.ProtoReflect.Descriptor instead.
+func (*GetCorkStatusRequest) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{8}
+}
+
+func (x *GetCorkStatusRequest) GetCorkId() string {
+	if x != nil {
+		return x.CorkId
+	}
+	return ""
+}
+
+func (x *GetCorkStatusRequest) GetTxHash() string {
+	if x != nil {
+		return x.TxHash
+	}
+	return ""
+}
+
+//
+// Represents the status of a cork
+type GetCorkStatusResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Status CorkStatus `protobuf:"varint,1,opt,name=status,proto3,enum=steward.v2.CorkStatus" json:"status,omitempty"`
+	// Height of the block in which the submission transaction was included, if known
+	Height int64 `protobuf:"varint,2,opt,name=height,proto3" json:"height,omitempty"`
+	// Result code of the submission transaction, if known
+	Code uint32 `protobuf:"varint,3,opt,name=code,proto3" json:"code,omitempty"`
+}
+
+func (x *GetCorkStatusResponse) Reset() {
+	*x = GetCorkStatusResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_steward_proto_msgTypes[9]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetCorkStatusResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetCorkStatusResponse) ProtoMessage() {}
+
+func (x *GetCorkStatusResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_steward_proto_msgTypes[9]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetCorkStatusResponse.ProtoReflect.Descriptor instead.
+func (*GetCorkStatusResponse) Descriptor() ([]byte, []int) {
+	return file_steward_proto_rawDescGZIP(), []int{9}
+}
+
+func (x *GetCorkStatusResponse) GetStatus() CorkStatus {
+	if x != nil {
+		return x.Status
+	}
+	return CorkStatus_CORK_STATUS_UNSPECIFIED
+}
+
+func (x *GetCorkStatusResponse) GetHeight() int64 {
+	if x != nil {
+		return x.Height
+	}
+	return 0
+}
+
+func (x *GetCorkStatusResponse) GetCode() uint32 {
+	if x != nil {
+		return x.Code
+	}
+	return 0
+}
+
 var File_steward_proto protoreflect.FileDescriptor
 
 var file_steward_proto_rawDesc = []byte{
 	0x0a, 0x0d, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12,
 	0x0a, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x1a, 0x18, 0x61, 0x61, 0x76,
 	0x65, 0x5f, 0x76, 0x32, 0x5f, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x2e,
-	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x87, 0x01, 0x0a, 0x0d, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x63, 0x65, 0x6c, 0x6c, 0x61,
-	0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x63, 0x65, 0x6c, 0x6c,
-	0x61, 0x72, 0x49, 0x64, 0x12, 0x4c, 0x0a, 0x12, 0x61, 0x61, 0x76, 0x65, 0x5f, 0x76, 0x32, 0x5f,
-	0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x1c, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61,
-	0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x48, 0x00,
-	0x52, 0x10, 0x61, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f,
-	0x69, 0x6e, 0x42, 0x0b, 0x0a, 0x09, 0x63, 0x61, 0x6c, 0x6c, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x22,
-	0x10, 0x0a, 0x0e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-	0x65, 0x32, 0x51, 0x0a, 0x0c, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x61, 0x6c,
-	0x6c, 0x12, 0x41, 0x0a, 0x06, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x12, 0x19, 0x2e, 0x73, 0x74,
-	0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x52,
-	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64,
-	0x2e, 0x76, 0x32, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x13, 0x65, 0x72, 0x63, 0x34, 0x36, 0x32, 0x36, 0x5f, 0x76,
+	0x61, 0x75, 0x6c, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xdb, 0x02, 0x0a, 0x0d, 0x53,
+	0x75, 0x62, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09,
+	0x63, 0x65, 0x6c, 0x6c, 0x61, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x08, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x72, 0x49, 0x64, 0x12, 0x4c, 0x0a, 0x12, 0x61, 0x61, 0x76,
+	0x65, 0x5f, 0x76, 0x32, 0x5f, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e,
+	0x76, 0x32, 0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63,
+	0x6f, 0x69, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x61, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61,
+	0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x12, 0x3f, 0x0a, 0x0d, 0x65, 0x72, 0x63, 0x34, 0x36,
+	0x32, 0x36, 0x5f, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18,
+	0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x45, 0x72, 0x63, 0x34,
+	0x36, 0x32, 0x36, 0x56, 0x61, 0x75, 0x6c, 0x74, 0x48, 0x00, 0x52, 0x0c, 0x65, 0x72, 0x63, 0x34,
+	0x36, 0x32, 0x36, 0x56, 0x61, 0x75, 0x6c, 0x74, 0x12, 0x68, 0x0a, 0x1c, 0x61, 0x61, 0x76, 0x65,
+	0x5f, 0x76, 0x32, 0x5f, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x5f, 0x6d,
+	0x75, 0x6c, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25,
+	0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61, 0x76, 0x65,
+	0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x4d, 0x75, 0x6c, 0x74,
+	0x69, 0x63, 0x61, 0x6c, 0x6c, 0x48, 0x00, 0x52, 0x19, 0x61, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53,
+	0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x63, 0x61,
+	0x6c, 0x6c, 0x12, 0x27, 0x0a, 0x0f, 0x69, 0x64, 0x65, 0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63,
+	0x79, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0e, 0x69, 0x64, 0x65,
+	0x6d, 0x70, 0x6f, 0x74, 0x65, 0x6e, 0x63, 0x79, 0x4b, 0x65, 0x79, 0x42, 0x0b, 0x0a, 0x09, 0x63,
+	0x61, 0x6c, 0x6c, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x22, 0x6e, 0x0a, 0x0e, 0x53, 0x75, 0x62, 0x6d,
+	0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x17, 0x0a, 0x07, 0x63, 0x6f,
+	0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x63, 0x6f, 0x72,
+	0x6b, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x16, 0x0a, 0x06,
+	0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x68, 0x65,
+	0x69, 0x67, 0x68, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x0d, 0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x22, 0x69, 0x0a, 0x0f, 0x53, 0x63, 0x68, 0x65,
+	0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x33, 0x0a, 0x07, 0x72,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x73,
+	0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x52, 0x07, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x65, 0x69,
+	0x67, 0x68, 0x74, 0x22, 0x93, 0x01, 0x0a, 0x10, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x17, 0x0a, 0x07, 0x63, 0x6f, 0x72, 0x6b,
+	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x63, 0x6f, 0x72, 0x6b, 0x49,
+	0x64, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x65,
+	0x69, 0x67, 0x68, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x68, 0x65, 0x69, 0x67,
+	0x68, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d,
+	0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
+	0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
+	0x6f, 0x63, 0x6b, 0x48, 0x65, 0x69, 0x67, 0x68, 0x74, 0x22, 0x38, 0x0a, 0x19, 0x4c, 0x69, 0x73,
+	0x74, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x43, 0x6f, 0x72, 0x6b, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x72,
+	0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x63, 0x65, 0x6c, 0x6c, 0x61,
+	0x72, 0x49, 0x64, 0x22, 0x4d, 0x0a, 0x1a, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x63, 0x68, 0x65, 0x64,
+	0x75, 0x6c, 0x65, 0x64, 0x43, 0x6f, 0x72, 0x6b, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+	0x65, 0x12, 0x2f, 0x0a, 0x05, 0x63, 0x6f, 0x72, 0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+	0x32, 0x19, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x63,
+	0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x43, 0x6f, 0x72, 0x6b, 0x52, 0x05, 0x63, 0x6f, 0x72,
+	0x6b, 0x73, 0x22, 0xe9, 0x01, 0x0a, 0x0d, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64,
+	0x43, 0x6f, 0x72, 0x6b, 0x12, 0x17, 0x0a, 0x07, 0x63, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x63, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x12, 0x1b, 0x0a,
+	0x09, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x08, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x72, 0x49, 0x64, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c,
+	0x6f, 0x63, 0x6b, 0x5f, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x65, 0x69, 0x67, 0x68, 0x74, 0x12, 0x32, 0x0a,
+	0x15, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+	0x74, 0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x13, 0x65, 0x6e,
+	0x63, 0x6f, 0x64, 0x65, 0x64, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x61, 0x6c,
+	0x6c, 0x12, 0x2d, 0x0a, 0x12, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x73, 0x69,
+	0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x11, 0x66,
+	0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
+	0x12, 0x1c, 0x0a, 0x09, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x06, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x09, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x22, 0xc6,
+	0x01, 0x0a, 0x10, 0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x17, 0x0a, 0x07, 0x63, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x63, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x12, 0x36, 0x0a, 0x17,
+	0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f,
+	0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x15, 0x74,
+	0x61, 0x72, 0x67, 0x65, 0x74, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64,
+	0x72, 0x65, 0x73, 0x73, 0x12, 0x32, 0x0a, 0x15, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x5f,
+	0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x13, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x43, 0x6f, 0x6e, 0x74,
+	0x72, 0x61, 0x63, 0x74, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x2d, 0x0a, 0x12, 0x66, 0x75, 0x6e, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x04,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x11, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x69,
+	0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x48, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x43, 0x6f,
+	0x72, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x17, 0x0a, 0x07, 0x63, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+	0x52, 0x06, 0x63, 0x6f, 0x72, 0x6b, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68,
+	0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73,
+	0x68, 0x22, 0x73, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74,
+	0x75, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2e, 0x0a, 0x06, 0x73, 0x74,
+	0x61, 0x74, 0x75, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x16, 0x2e, 0x73, 0x74, 0x65,
+	0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x43, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74,
+	0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x65,
+	0x69, 0x67, 0x68, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x06, 0x68, 0x65, 0x69, 0x67,
+	0x68, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d,
+	0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x2a, 0xa8, 0x01, 0x0a, 0x0a, 0x43, 0x6f, 0x72, 0x6b, 0x53,
+	0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1b, 0x0a, 0x17, 0x43, 0x4f, 0x52, 0x4b, 0x5f, 0x53, 0x54,
+	0x41, 0x54, 0x55, 0x53, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
+	0x10, 0x00, 0x12, 0x16, 0x0a, 0x12, 0x43, 0x4f, 0x52, 0x4b, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55,
+	0x53, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x01, 0x12, 0x18, 0x0a, 0x14, 0x43, 0x4f,
+	0x52, 0x4b, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x49, 0x4e, 0x43, 0x4c, 0x55, 0x44,
+	0x45, 0x44, 0x10, 0x02, 0x12, 0x17, 0x0a, 0x13, 0x43, 0x4f, 0x52, 0x4b, 0x5f, 0x53, 0x54, 0x41,
+	0x54, 0x55, 0x53, 0x5f, 0x50, 0x45, 0x4e, 0x44, 0x49, 0x4e, 0x47, 0x10, 0x03, 0x12, 0x17, 0x0a,
+	0x13, 0x43, 0x4f, 0x52, 0x4b, 0x5f, 0x53, 0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x42, 0x52, 0x49,
+	0x44, 0x47, 0x45, 0x44, 0x10, 0x04, 0x12, 0x19, 0x0a, 0x15, 0x43, 0x4f, 0x52, 0x4b, 0x5f, 0x53,
+	0x54, 0x41, 0x54, 0x55, 0x53, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x46, 0x4f, 0x55, 0x4e, 0x44, 0x10,
+	0x05, 0x32, 0xa0, 0x03, 0x0a, 0x0c, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x61,
+	0x6c, 0x6c, 0x12, 0x41, 0x0a, 0x06, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x12, 0x19, 0x2e, 0x73,
+	0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72,
+	0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x56, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x72, 0x6b,
+	0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x20, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64,
+	0x2e, 0x76, 0x32, 0x2e, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x75,
+	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x21, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61,
+	0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x72, 0x6b, 0x53, 0x74, 0x61,
+	0x74, 0x75, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x45, 0x0a,
+	0x08, 0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x65, 0x12, 0x19, 0x2e, 0x73, 0x74, 0x65, 0x77,
+	0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76,
+	0x32, 0x2e, 0x53, 0x69, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x22, 0x00, 0x12, 0x47, 0x0a, 0x08, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65,
+	0x12, 0x1b, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x63,
+	0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e,
+	0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x63, 0x68, 0x65, 0x64,
+	0x75, 0x6c, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x65, 0x0a,
+	0x12, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x43, 0x6f,
+	0x72, 0x6b, 0x73, 0x12, 0x25, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32,
+	0x2e, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75, 0x6c, 0x65, 0x64, 0x43, 0x6f,
+	0x72, 0x6b, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x26, 0x2e, 0x73, 0x74, 0x65,
+	0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x53, 0x63, 0x68, 0x65,
+	0x64, 0x75, 0x6c, 0x65, 0x64, 0x43, 0x6f, 0x72, 0x6b, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
 	0x73, 0x65, 0x22, 0x00, 0x42, 0x10, 0x5a, 0x0e, 0x2f, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64,
 	0x5f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
@@ -180,21 +980,46 @@ func file_steward_proto_rawDescGZIP() []byte {
 	return file_steward_proto_rawDescData
 }
 
-var file_steward_proto_msgTypes = make([]protoimpl.MessageInfo, 2)
+var file_steward_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
+var file_steward_proto_msgTypes = make([]protoimpl.MessageInfo, 10)
 var file_steward_proto_goTypes = []interface{}{
-	(*SubmitRequest)(nil),    // 0: steward.v2.SubmitRequest
-	(*SubmitResponse)(nil),   // 1: steward.v2.SubmitResponse
-	(*AaveV2Stablecoin)(nil), // 2: steward.v2.AaveV2Stablecoin
+	(CorkStatus)(0),                    // 0: steward.v2.CorkStatus
+	(*SubmitRequest)(nil),              // 1: steward.v2.SubmitRequest
+	(*SubmitResponse)(nil),             // 2: steward.v2.SubmitResponse
+	(*ScheduleRequest)(nil),            // 3: steward.v2.ScheduleRequest
+	(*ScheduleResponse)(nil),           // 4: steward.v2.ScheduleResponse
+	(*ListScheduledCorksRequest)(nil),  // 5: steward.v2.ListScheduledCorksRequest
+	(*ListScheduledCorksResponse)(nil), // 6: steward.v2.ListScheduledCorksResponse
+	(*ScheduledCork)(nil),              // 7: steward.v2.ScheduledCork
+	(*SimulateResponse)(nil),           // 8: steward.v2.SimulateResponse
+	(*GetCorkStatusRequest)(nil),       // 9: steward.v2.GetCorkStatusRequest
+	(*GetCorkStatusResponse)(nil),      // 10: steward.v2.GetCorkStatusResponse
+	(*AaveV2Stablecoin)(nil),           // 11: steward.v2.AaveV2Stablecoin
+	(*Erc4626Vault)(nil),               // 12: steward.v2.Erc4626Vault
+	(*AaveV2StablecoinMulticall)(nil),  // 13: steward.v2.AaveV2StablecoinMulticall
 }
 var file_steward_proto_depIdxs = []int32{
-	2, // 0: steward.v2.SubmitRequest.aave_v2_stablecoin:type_name -> steward.v2.AaveV2Stablecoin
-	0, // 1: steward.v2.ContractCall.Submit:input_type -> steward.v2.SubmitRequest
-	1, // 2: steward.v2.ContractCall.Submit:output_type -> steward.v2.SubmitResponse
-	2, // [2:3] is the sub-list for method output_type
-	1, // [1:2] is the sub-list for method input_type
-	1, // [1:1] is the sub-list for extension type_name
-	1, // [1:1] is the sub-list for extension extendee
-	0, // [0:1] is the sub-list for field type_name
+	11, // 0: steward.v2.SubmitRequest.aave_v2_stablecoin:type_name -> steward.v2.AaveV2Stablecoin
+	12, // 1: steward.v2.SubmitRequest.erc4626_vault:type_name -> steward.v2.Erc4626Vault
+	13, // 2: steward.v2.SubmitRequest.aave_v2_stablecoin_multicall:type_name -> steward.v2.AaveV2StablecoinMulticall
+	1,  // 3: steward.v2.ScheduleRequest.request:type_name -> steward.v2.SubmitRequest
+	7,  // 4: steward.v2.ListScheduledCorksResponse.corks:type_name -> steward.v2.ScheduledCork
+	0,  // 5: steward.v2.GetCorkStatusResponse.status:type_name -> steward.v2.CorkStatus
+	1,  // 6: steward.v2.ContractCall.Submit:input_type -> steward.v2.SubmitRequest
+	9,  // 7: steward.v2.ContractCall.GetCorkStatus:input_type -> steward.v2.GetCorkStatusRequest
+	1,  // 8: steward.v2.ContractCall.Simulate:input_type -> steward.v2.SubmitRequest
+	3,  // 9: steward.v2.ContractCall.Schedule:input_type -> steward.v2.ScheduleRequest
+	5,  // 10: steward.v2.ContractCall.ListScheduledCorks:input_type -> steward.v2.ListScheduledCorksRequest
+	2,  // 11: steward.v2.ContractCall.Submit:output_type -> steward.v2.SubmitResponse
+	10, // 12: steward.v2.ContractCall.GetCorkStatus:output_type -> steward.v2.GetCorkStatusResponse
+	8,  // 13: steward.v2.ContractCall.Simulate:output_type -> steward.v2.SimulateResponse
+	4,  // 14: steward.v2.ContractCall.Schedule:output_type -> steward.v2.ScheduleResponse
+	6,  // 15: steward.v2.ContractCall.ListScheduledCorks:output_type -> steward.v2.ListScheduledCorksResponse
+	11, // [11:16] is the sub-list for method output_type
+	6,  // [6:11] is the sub-list for method input_type
+	6,  // [6:6] is the sub-list for extension type_name
+	6,  // [6:6] is the sub-list for extension extendee
+	0,  // [0:6] is the sub-list for field type_name
 }
 
 func init() { file_steward_proto_init() }
@@ -203,6 +1028,7 @@ func file_steward_proto_init() {
 		return
 	}
 	file_aave_v2_stablecoin_proto_init()
+	file_erc4626_vault_proto_init()
 	if !protoimpl.UnsafeEnabled {
 		file_steward_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*SubmitRequest); i {
@@ -228,22 +1054,121 @@ func file_steward_proto_init() {
 				return nil
 			}
 		}
+		file_steward_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ScheduleRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_steward_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ScheduleResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_steward_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListScheduledCorksRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_steward_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ListScheduledCorksResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_steward_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ScheduledCork); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_steward_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SimulateResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_steward_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetCorkStatusRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_steward_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetCorkStatusResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
 	}
 	file_steward_proto_msgTypes[0].OneofWrappers = []interface{}{
 		(*SubmitRequest_AaveV2Stablecoin)(nil),
+		(*SubmitRequest_Erc4626Vault)(nil),
+		(*SubmitRequest_AaveV2StablecoinMulticall)(nil),
 	}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_steward_proto_rawDesc,
-			NumEnums:      0,
-			NumMessages:   2,
+			NumEnums:      1,
+			NumMessages:   10,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
 		GoTypes:           file_steward_proto_goTypes,
 		DependencyIndexes: file_steward_proto_depIdxs,
+		EnumInfos:         file_steward_proto_enumTypes,
 		MessageInfos:      file_steward_proto_msgTypes,
 	}.Build()
 	File_steward_proto = out.File
@@ -24,6 +24,14 @@ const _ = grpc.SupportPackageIsVersion7
 type ContractCallClient interface {
 	// Handles simple contract call submission
 	Submit(ctx context.Context, in *SubmitRequest, opts ...grpc.CallOption) (*SubmitResponse, error)
+	// Reports the status of a previously submitted cork
+	GetCorkStatus(ctx context.Context, in *GetCorkStatusRequest, opts ...grpc.CallOption) (*GetCorkStatusResponse, error)
+	// Encodes a contract call as Submit would without signing or sending it to Sommelier
+	Simulate(ctx context.Context, in *SubmitRequest, opts ...grpc.CallOption) (*SimulateResponse, error)
+	// Handles contract call submission to be executed at a future block height
+	Schedule(ctx context.Context, in *ScheduleRequest, opts ...grpc.CallOption) (*ScheduleResponse, error)
+	// Lists the corks scheduled by this Steward's validator that have not yet been submitted
+	ListScheduledCorks(ctx context.Context, in *ListScheduledCorksRequest, opts ...grpc.CallOption) (*ListScheduledCorksResponse, error)
 }
 
 type contractCallClient struct {
@@ -43,12 +51,56 @@ func (c *contractCallClient) Submit(ctx context.Context, in *SubmitRequest, opts
 	return out, nil
 }
 
+func (c *contractCallClient) GetCorkStatus(ctx context.Context, in *GetCorkStatusRequest, opts ...grpc.CallOption) (*GetCorkStatusResponse, error) {
+	out := new(GetCorkStatusResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/GetCorkStatus", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) Simulate(ctx context.Context, in *SubmitRequest, opts ...grpc.CallOption) (*SimulateResponse, error) {
+	out := new(SimulateResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/Simulate", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) Schedule(ctx context.Context, in *ScheduleRequest, opts ...grpc.CallOption) (*ScheduleResponse, error) {
+	out := new(ScheduleResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/Schedule", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) ListScheduledCorks(ctx context.Context, in *ListScheduledCorksRequest, opts ...grpc.CallOption) (*ListScheduledCorksResponse, error) {
+	out := new(ListScheduledCorksResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/ListScheduledCorks", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // ContractCallServer is the server API for ContractCall service.
 // All implementations must embed UnimplementedContractCallServer
 // for forward compatibility
 type ContractCallServer interface {
 	// Handles simple contract call submission
 	Submit(context.Context, *SubmitRequest) (*SubmitResponse, error)
+	// Reports the status of a previously submitted cork
+	GetCorkStatus(context.Context, *GetCorkStatusRequest) (*GetCorkStatusResponse, error)
+	// Encodes a contract call as Submit would without signing or sending it to Sommelier
+	Simulate(context.Context, *SubmitRequest) (*SimulateResponse, error)
+	// Handles contract call submission to be executed at a future block height
+	Schedule(context.Context, *ScheduleRequest) (*ScheduleResponse, error)
+	// Lists the corks scheduled by this Steward's validator that have not yet been submitted
+	ListScheduledCorks(context.Context, *ListScheduledCorksRequest) (*ListScheduledCorksResponse, error)
 	mustEmbedUnimplementedContractCallServer()
 }
 
@@ -59,6 +111,18 @@ type UnimplementedContractCallServer struct {
 func (UnimplementedContractCallServer) Submit(context.Context, *SubmitRequest) (*SubmitResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Submit not implemented")
 }
+func (UnimplementedContractCallServer) GetCorkStatus(context.Context, *GetCorkStatusRequest) (*GetCorkStatusResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetCorkStatus not implemented")
+}
+func (UnimplementedContractCallServer) Simulate(context.Context, *SubmitRequest) (*SimulateResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Simulate not implemented")
+}
+func (UnimplementedContractCallServer) Schedule(context.Context, *ScheduleRequest) (*ScheduleResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Schedule not implemented")
+}
+func (UnimplementedContractCallServer) ListScheduledCorks(context.Context, *ListScheduledCorksRequest) (*ListScheduledCorksResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListScheduledCorks not implemented")
+}
 func (UnimplementedContractCallServer) mustEmbedUnimplementedContractCallServer() {}
 
 // UnsafeContractCallServer may be embedded to opt out of forward compatibility for this service.
@@ -90,6 +154,78 @@ func _ContractCall_Submit_Handler(srv interface{}, ctx context.Context, dec func
 	return interceptor(ctx, in, info, handler)
 }
 
+func _ContractCall_GetCorkStatus_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetCorkStatusRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).GetCorkStatus(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/GetCorkStatus",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).GetCorkStatus(ctx, req.(*GetCorkStatusRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_Simulate_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SubmitRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).Simulate(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/Simulate",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).Simulate(ctx, req.(*SubmitRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_Schedule_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ScheduleRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).Schedule(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/Schedule",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).Schedule(ctx, req.(*ScheduleRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_ListScheduledCorks_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListScheduledCorksRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).ListScheduledCorks(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/ListScheduledCorks",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).ListScheduledCorks(ctx, req.(*ListScheduledCorksRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // ContractCall_ServiceDesc is the grpc.ServiceDesc for ContractCall service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -101,6 +237,22 @@ var ContractCall_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Submit",
 			Handler:    _ContractCall_Submit_Handler,
 		},
+		{
+			MethodName: "GetCorkStatus",
+			Handler:    _ContractCall_GetCorkStatus_Handler,
+		},
+		{
+			MethodName: "Simulate",
+			Handler:    _ContractCall_Simulate_Handler,
+		},
+		{
+			MethodName: "Schedule",
+			Handler:    _ContractCall_Schedule_Handler,
+		},
+		{
+			MethodName: "ListScheduledCorks",
+			Handler:    _ContractCall_ListScheduledCorks_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "steward.proto",
@@ -183,14 +183,82 @@ pub mod aave_v2_stablecoin {
     }
 }
 ///
+/// Represents a batch of function calls to the Aave V2 Stablecoin cellar, executed atomically in order through a single
+/// cork.
+///
+/// Represents function `multicall(bytes[] data)`
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AaveV2StablecoinMulticall {
+    /// The function calls to execute, in order
+    #[prost(message, repeated, tag = "1")]
+    pub function_calls: ::prost::alloc::vec::Vec<AaveV2Stablecoin>,
+}
+///
+/// Represents a function call to an ERC-4626 vault cellar. Corks are executed with the Gravity contract as the caller, so
+/// only functions that return assets to the cellar are supported.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Erc4626Vault {
+    /// The function you wish to execute on the target cellar
+    #[prost(oneof = "erc4626_vault::Function", tags = "1, 2")]
+    pub function: ::core::option::Option<erc4626_vault::Function>,
+}
+/// Nested message and enum types in `Erc4626Vault`.
+pub mod erc4626_vault {
+    ///
+    /// Burns shares from the owner and sends exactly `assets` to the receiver.
+    ///
+    /// Represents function `withdraw(uint256 assets, address receiver, address owner)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Withdraw {
+        /// Amount of assets to withdraw. Must be parsable as an unsigned 256-bit integer.
+        #[prost(string, tag = "1")]
+        pub assets: ::prost::alloc::string::String,
+        /// Address that will receive the withdrawn assets. Must be the target cellar.
+        #[prost(string, tag = "2")]
+        pub receiver: ::prost::alloc::string::String,
+        /// Address whose shares will be burned. Must not be the Gravity contract.
+        #[prost(string, tag = "3")]
+        pub owner: ::prost::alloc::string::String,
+    }
+    ///
+    /// Burns exactly `shares` from the owner and sends the resulting assets to the receiver.
+    ///
+    /// Represents function `redeem(uint256 shares, address receiver, address owner)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Redeem {
+        /// Amount of shares to redeem. Must be parsable as an unsigned 256-bit integer.
+        #[prost(string, tag = "1")]
+        pub shares: ::prost::alloc::string::String,
+        /// Address that will receive the redeemed assets. Must be the target cellar.
+        #[prost(string, tag = "2")]
+        pub receiver: ::prost::alloc::string::String,
+        /// Address whose shares will be burned. Must not be the Gravity contract.
+        #[prost(string, tag = "3")]
+        pub owner: ::prost::alloc::string::String,
+    }
+    /// The function you wish to execute on the target cellar
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Function {
+        /// Represents function `withdraw(uint256 assets, address receiver, address owner)`
+        #[prost(message, tag = "1")]
+        Withdraw(Withdraw),
+        /// Represents function `redeem(uint256 shares, address receiver, address owner)`
+        #[prost(message, tag = "2")]
+        Redeem(Redeem),
+    }
+}
+///
 /// Represents a single function call on a particular Cellar
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SubmitRequest {
     /// The ID (currently simply an Ethereum address) of the target Cellar
     #[prost(string, tag = "1")]
     pub cellar_id: ::prost::alloc::string::String,
+    /// Optional key identifying this submission. A retried request with the same key from the same client returns the result of the original request instead of submitting the cork again.
+    #[prost(string, tag = "5")]
+    pub idempotency_key: ::prost::alloc::string::String,
     /// The data from which the desired contract function will be encoded
-    #[prost(oneof = "submit_request::CallData", tags = "2")]
+    #[prost(oneof = "submit_request::CallData", tags = "2, 3, 4")]
     pub call_data: ::core::option::Option<submit_request::CallData>,
 }
 /// Nested message and enum types in `SubmitRequest`.
@@ -200,10 +268,157 @@ pub mod submit_request {
     pub enum CallData {
         #[prost(message, tag = "2")]
         AaveV2Stablecoin(super::AaveV2Stablecoin),
+        #[prost(message, tag = "3")]
+        Erc4626Vault(super::Erc4626Vault),
+        #[prost(message, tag = "4")]
+        AaveV2StablecoinMulticall(super::AaveV2StablecoinMulticall),
     }
 }
+///
+/// Represents the result of a cork submission
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitResponse {
+    /// Hex-encoded keccak256 hash of the target contract address and encoded contract call. The same value is used by the Gravity bridge as the invalidation scope of the resulting contract call.
+    #[prost(string, tag = "1")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// Hash of the transaction that submitted the cork to Sommelier
+    #[prost(string, tag = "2")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// Height of the block in which the transaction was included
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+    /// Result code of the transaction. Zero indicates success.
+    #[prost(uint32, tag = "4")]
+    pub code: u32,
+}
+///
+/// Represents a single function call on a particular Cellar, scheduled for a block height
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduleRequest {
+    /// The contract call, as it would be passed to Submit. The idempotency key is ignored.
+    #[prost(message, optional, tag = "1")]
+    pub request: ::core::option::Option<SubmitRequest>,
+    /// The Sommelier block height at which the cork module will submit the cork. Must be in the future and no more than the Steward's configured horizon ahead of the current height.
+    #[prost(uint64, tag = "2")]
+    pub block_height: u64,
+}
+///
+/// Represents the result of scheduling a cork
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduleResponse {
+    /// Hex-encoded keccak256 hash of the target contract address and encoded contract call
+    #[prost(string, tag = "1")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// Hash of the transaction that scheduled the cork on Sommelier
+    #[prost(string, tag = "2")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// Height of the block in which the transaction was included
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+    /// Result code of the transaction. Zero indicates success.
+    #[prost(uint32, tag = "4")]
+    pub code: u32,
+    /// The block height the cork is scheduled for
+    #[prost(uint64, tag = "5")]
+    pub block_height: u64,
+}
+///
+/// Represents a query for pending scheduled corks
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListScheduledCorksRequest {
+    /// (Optional) Only list corks targeting this Cellar
+    #[prost(string, tag = "1")]
+    pub cellar_id: ::prost::alloc::string::String,
+}
+///
+/// Represents the pending scheduled corks, ordered by block height
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SubmitResponse {}
+pub struct ListScheduledCorksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub corks: ::prost::alloc::vec::Vec<ScheduledCork>,
+}
+///
+/// Represents a cork scheduled for a block height
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledCork {
+    /// Hex-encoded keccak256 hash of the target contract address and encoded contract call
+    #[prost(string, tag = "1")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// The ID of the target Cellar
+    #[prost(string, tag = "2")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// The block height at which the cork module will submit the cork
+    #[prost(uint64, tag = "3")]
+    pub block_height: u64,
+    /// The ABI-encoded contract call
+    #[prost(bytes = "vec", tag = "4")]
+    pub encoded_contract_call: ::prost::alloc::vec::Vec<u8>,
+    /// Signature of the encoded function, e.g. `initiateShutdown(bool)`. Empty if the call could not be decoded.
+    #[prost(string, tag = "5")]
+    pub function_signature: ::prost::alloc::string::String,
+    /// The decoded arguments as a comma separated list. Empty if the call could not be decoded.
+    #[prost(string, tag = "6")]
+    pub arguments: ::prost::alloc::string::String,
+}
+///
+/// Represents the cork that Submit would send for a given request
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateResponse {
+    /// Hex-encoded keccak256 hash of the target contract address and encoded contract call
+    #[prost(string, tag = "1")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// The address of the contract the cork targets
+    #[prost(string, tag = "2")]
+    pub target_contract_address: ::prost::alloc::string::String,
+    /// The ABI-encoded contract call
+    #[prost(bytes = "vec", tag = "3")]
+    pub encoded_contract_call: ::prost::alloc::vec::Vec<u8>,
+    /// Signature of the encoded function, e.g. `rebalance(address[9],uint256[3][4],uint256)`
+    #[prost(string, tag = "4")]
+    pub function_signature: ::prost::alloc::string::String,
+}
+///
+/// Represents a query for the status of a cork
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCorkStatusRequest {
+    /// The cork ID returned by Submit
+    #[prost(string, tag = "1")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// (Optional) The transaction hash returned by Submit. Required to report CORK_STATUS_INCLUDED and CORK_STATUS_FAILED.
+    #[prost(string, tag = "2")]
+    pub tx_hash: ::prost::alloc::string::String,
+}
+///
+/// Represents the status of a cork
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCorkStatusResponse {
+    #[prost(enumeration = "CorkStatus", tag = "1")]
+    pub status: i32,
+    /// Height of the block in which the submission transaction was included, if known
+    #[prost(int64, tag = "2")]
+    pub height: i64,
+    /// Result code of the submission transaction, if known
+    #[prost(uint32, tag = "3")]
+    pub code: u32,
+}
+///
+/// Lifecycle stages of a cork
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CorkStatus {
+    /// Unused default value
+    Unspecified = 0,
+    /// The submission transaction failed
+    Failed = 1,
+    /// The submission transaction was included in a block
+    Included = 2,
+    /// The cork is awaiting the end of the cork module's current vote period
+    Pending = 3,
+    /// The cork was approved by the validator set and handed to the Gravity bridge to be relayed to Ethereum
+    Bridged = 4,
+    /// The cork was not found in the submission transaction, the cork module, or the Gravity bridge
+    NotFound = 5,
+}
 #[doc = r" Generated client implementations."]
 pub mod contract_call_client {
     #![allow(unused_variables, dead_code, missing_docs)]
@@ -254,6 +469,68 @@ pub mod contract_call_client {
             let path = http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/Submit");
             self.inner.unary(request.into_request(), path, codec).await
         }
+        #[doc = " Reports the status of a previously submitted cork"]
+        pub async fn get_cork_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCorkStatusRequest>,
+        ) -> Result<tonic::Response<super::GetCorkStatusResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/GetCorkStatus");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Encodes a contract call as Submit would without signing or sending it to Sommelier"]
+        pub async fn simulate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubmitRequest>,
+        ) -> Result<tonic::Response<super::SimulateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/Simulate");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Handles contract call submission to be executed at a future block height"]
+        pub async fn schedule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ScheduleRequest>,
+        ) -> Result<tonic::Response<super::ScheduleResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/Schedule");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Lists the corks scheduled by this Steward's validator that have not yet been submitted"]
+        pub async fn list_scheduled_corks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListScheduledCorksRequest>,
+        ) -> Result<tonic::Response<super::ListScheduledCorksResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/ListScheduledCorks");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
     impl<T: Clone> Clone for ContractCallClient<T> {
         fn clone(&self) -> Self {
@@ -280,6 +557,26 @@ pub mod contract_call_server {
             &self,
             request: tonic::Request<super::SubmitRequest>,
         ) -> Result<tonic::Response<super::SubmitResponse>, tonic::Status>;
+        #[doc = " Reports the status of a previously submitted cork"]
+        async fn get_cork_status(
+            &self,
+            request: tonic::Request<super::GetCorkStatusRequest>,
+        ) -> Result<tonic::Response<super::GetCorkStatusResponse>, tonic::Status>;
+        #[doc = " Encodes a contract call as Submit would without signing or sending it to Sommelier"]
+        async fn simulate(
+            &self,
+            request: tonic::Request<super::SubmitRequest>,
+        ) -> Result<tonic::Response<super::SimulateResponse>, tonic::Status>;
+        #[doc = " Handles contract call submission to be executed at a future block height"]
+        async fn schedule(
+            &self,
+            request: tonic::Request<super::ScheduleRequest>,
+        ) -> Result<tonic::Response<super::ScheduleResponse>, tonic::Status>;
+        #[doc = " Lists the corks scheduled by this Steward's validator that have not yet been submitted"]
+        async fn list_scheduled_corks(
+            &self,
+            request: tonic::Request<super::ListScheduledCorksRequest>,
+        ) -> Result<tonic::Response<super::ListScheduledCorksResponse>, tonic::Status>;
     }
     #[doc = ""]
     #[doc = " Service for handling Cellar contract calls"]
@@ -346,6 +643,135 @@ pub mod contract_call_server {
                     };
                     Box::pin(fut)
                 }
+                "/steward.v2.ContractCall/GetCorkStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCorkStatusSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::GetCorkStatusRequest>
+                        for GetCorkStatusSvc<T>
+                    {
+                        type Response = super::GetCorkStatusResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCorkStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_cork_status(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetCorkStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/Simulate" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::SubmitRequest> for SimulateSvc<T> {
+                        type Response = super::SimulateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubmitRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).simulate(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = SimulateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/Schedule" => {
+                    #[allow(non_camel_case_types)]
+                    struct ScheduleSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::ScheduleRequest> for ScheduleSvc<T> {
+                        type Response = super::ScheduleResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ScheduleRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).schedule(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ScheduleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/ListScheduledCorks" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListScheduledCorksSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall>
+                        tonic::server::UnaryService<super::ListScheduledCorksRequest>
+                        for ListScheduledCorksSvc<T>
+                    {
+                        type Response = super::ListScheduledCorksResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListScheduledCorksRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_scheduled_corks(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ListScheduledCorksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     Ok(http::Response::builder()
                         .status(200)